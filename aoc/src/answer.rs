@@ -0,0 +1,91 @@
+use serde_json::Value;
+use std::fmt::Display;
+
+/// result of a part, its [`Display`] output is the answer
+pub trait Answer: Display {
+    /// structured information on how the answer was found
+    fn details(&self) -> Option<Value> {
+        None
+    }
+}
+
+macro_rules! plain_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {})*
+    };
+}
+
+plain_answer!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, String, &str);
+
+/// answer together with [`Answer::details`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Detailed<T> {
+    pub answer: T,
+    pub details: Value,
+}
+
+impl<T: Display> Display for Detailed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.answer.fmt(f)
+    }
+}
+
+impl<T: Display> Answer for Detailed<T> {
+    fn details(&self) -> Option<Value> {
+        Some(self.details.clone())
+    }
+}
+
+/// like [`Detailed`], but the details are only built when they are asked for
+///
+/// The runner does this after it stopped timing the part, so expensive details don't
+/// count towards its duration.
+pub struct LazyDetailed<T, F> {
+    pub answer: T,
+    pub details: F,
+}
+
+impl<T: Display, F> Display for LazyDetailed<T, F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.answer.fmt(f)
+    }
+}
+
+impl<T: Display, F: Fn() -> Value> Answer for LazyDetailed<T, F> {
+    fn details(&self) -> Option<Value> {
+        Some((self.details)())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_detailed() {
+        let answer = Detailed {
+            answer: 23,
+            details: json!({ "x": 1 }),
+        };
+        assert_eq!(answer.to_string(), "23");
+        assert_eq!(answer.details(), Some(json!({ "x": 1 })));
+        assert_eq!(23.details(), None);
+    }
+
+    #[test]
+    fn test_lazy_detailed() {
+        let built = std::cell::Cell::new(false);
+        let answer = LazyDetailed {
+            answer: 23,
+            details: || {
+                built.set(true);
+                json!({ "x": 1 })
+            },
+        };
+        assert_eq!(answer.to_string(), "23");
+        assert!(!built.get());
+        assert_eq!(answer.details(), Some(json!({ "x": 1 })));
+        assert!(built.get());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::{GridNeighbours, Point};
+use grid::Grid;
+use std::ops::{BitAndAssign, BitOrAssign, Index};
+
+/// grid of booleans packed into bits, e.g. obstacles or visited cells
+///
+/// Cheap to clone and hashable by content, so it can be used as search state.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    rows: usize,
+    cols: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// grid of the given size with no cell set
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            words: vec![0; (rows * cols).div_ceil(64)],
+        }
+    }
+
+    /// set every cell for which `f` holds
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl Fn(&T) -> bool) -> Self {
+        let mut bits = Self::new(grid.rows(), grid.cols());
+        for ((line, column), cell) in grid.indexed_iter() {
+            if f(cell) {
+                bits.insert(Point(line, column));
+            }
+        }
+        bits
+    }
+
+    /// number of rows
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// number of columns
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// whether `p` is set, cells outside of the grid are never set
+    pub fn contains(&self, p: Point) -> bool {
+        self.bit(p)
+            .is_some_and(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    /// set `p`, returns whether it wasn't set before
+    pub fn insert(&mut self, p: Point) -> bool {
+        let (word, mask) = self.bit(p).expect("point is inside the grid");
+        let added = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        added
+    }
+
+    /// unset `p`, returns whether it was set before
+    pub fn remove(&mut self, p: Point) -> bool {
+        let (word, mask) = self.bit(p).expect("point is inside the grid");
+        let removed = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        removed
+    }
+
+    /// number of set cells
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// the set cells in reading order
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        (0..self.rows * self.cols)
+            .filter(|idx| self.words[idx / 64] & (1 << (idx % 64)) != 0)
+            .map(|idx| Point(idx / self.cols, idx % self.cols))
+    }
+
+    /// cells which are set in `self` or `other`
+    pub fn union(&self, other: &BitGrid) -> BitGrid {
+        let mut union = self.clone();
+        union |= other;
+        union
+    }
+
+    /// cells which are set in both `self` and `other`
+    pub fn intersection(&self, other: &BitGrid) -> BitGrid {
+        let mut intersection = self.clone();
+        intersection &= other;
+        intersection
+    }
+
+    /// dense copy, e.g. for rendering
+    pub fn to_grid(&self) -> Grid<bool> {
+        let mut grid = Grid::new(self.rows, self.cols);
+        for p in self.iter() {
+            grid[p] = true;
+        }
+        grid
+    }
+
+    fn bit(&self, Point(line, column): Point) -> Option<(usize, u64)> {
+        if line < self.rows && column < self.cols {
+            let idx = line * self.cols + column;
+            Some((idx / 64, 1 << (idx % 64)))
+        } else {
+            None
+        }
+    }
+
+    fn assert_same_size(&self, other: &BitGrid) {
+        assert_eq!(
+            (self.rows, self.cols),
+            (other.rows, other.cols),
+            "bit grids must have the same size"
+        );
+    }
+}
+
+impl BitOrAssign<&BitGrid> for BitGrid {
+    fn bitor_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        for (word, other) in self.words.iter_mut().zip(rhs.words.iter()) {
+            *word |= other;
+        }
+    }
+}
+
+impl BitAndAssign<&BitGrid> for BitGrid {
+    fn bitand_assign(&mut self, rhs: &BitGrid) {
+        self.assert_same_size(rhs);
+        for (word, other) in self.words.iter_mut().zip(rhs.words.iter()) {
+            *word &= other;
+        }
+    }
+}
+
+impl Index<Point> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, p: Point) -> &Self::Output {
+        if self.contains(p) {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl GridNeighbours for BitGrid {
+    fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_test() {
+        let mut bits = BitGrid::new(3, 30);
+        assert!(bits.insert(Point(2, 29)));
+        assert!(!bits.insert(Point(2, 29)));
+        assert!(bits.insert(Point(0, 1)));
+        assert!(bits.contains(Point(2, 29)));
+        assert!(bits[Point(0, 1)]);
+        assert!(!bits[Point(1, 1)]);
+        assert!(!bits.contains(Point(3, 0)));
+        assert_eq!(bits.count(), 2);
+        assert_eq!(bits.iter().collect::<Vec<_>>(), [Point(0, 1), Point(2, 29)]);
+        assert!(bits.remove(Point(0, 1)));
+        assert!(!bits.remove(Point(0, 1)));
+        assert_eq!(bits.count(), 1);
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let grid = Grid::from_vec(vec![1, 2, 3, 4, 5, 6], 3);
+        let even = BitGrid::from_grid(&grid, |n| n % 2 == 0);
+        let small = BitGrid::from_grid(&grid, |n| *n < 4);
+        assert_eq!(even.union(&small).count(), 5);
+        assert_eq!(
+            even.intersection(&small).iter().collect::<Vec<_>>(),
+            [Point(0, 1)]
+        );
+
+        let mut with_six = small.clone();
+        with_six.insert(Point(1, 2));
+        assert_ne!(with_six, small);
+        let set: std::collections::HashSet<_> = [small.clone(), with_six, small].into();
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::Point;
+use std::{fmt::Display, ops::Add};
+
+/// one of the four orthogonal directions on a grid, `Up` decreases the line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// all directions clockwise starting with `Up`
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// rotate by 90 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// rotate by 90 degrees counterclockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// offset of a single step as `Point(line, column)`
+    pub fn delta<T: From<i8>>(self) -> Point<T> {
+        let (line, column) = match self {
+            Direction::Up => (-1, 0),
+            Direction::Right => (0, 1),
+            Direction::Down => (1, 0),
+            Direction::Left => (0, -1),
+        };
+        Point(T::from(line), T::from(column))
+    }
+
+    /// the direction of a single step, `None` if `delta` is no such step
+    pub fn from_delta<T: From<i8> + PartialEq>(delta: Point<T>) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.delta::<T>() == delta)
+    }
+
+    /// `^`, `>`, `v` or `<`
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+/// parses arrows `^>v<`, compass points `NESW` and `UDLR`
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' | 'N' | 'U' => Ok(Direction::Up),
+            '>' | 'E' | 'R' => Ok(Direction::Right),
+            'v' | 'S' | 'D' => Ok(Direction::Down),
+            '<' | 'W' | 'L' => Ok(Direction::Left),
+            c => Err(format!("{c} is no direction")),
+        }
+    }
+}
+
+impl Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+impl From<Direction> for (i32, i32) {
+    fn from(value: Direction) -> Self {
+        value.delta::<i32>().into()
+    }
+}
+
+/// step into `rhs`, `None` if that leaves the positive coordinates
+impl Add<Direction> for Point {
+    type Output = Option<Point>;
+
+    fn add(self, rhs: Direction) -> Self::Output {
+        self + <(i32, i32)>::from(rhs)
+    }
+}
+
+/// one of the eight directions on a grid including the diagonals
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// all directions clockwise starting with `Up`
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// rotate by 45 degrees clockwise
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// rotate by 45 degrees counterclockwise
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// offset of a single step as `Point(line, column)`
+    pub fn delta<T: From<i8>>(self) -> Point<T> {
+        let (line, column) = match self {
+            Direction8::Up => (-1, 0),
+            Direction8::UpRight => (-1, 1),
+            Direction8::Right => (0, 1),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (1, 0),
+            Direction8::DownLeft => (1, -1),
+            Direction8::Left => (0, -1),
+            Direction8::UpLeft => (-1, -1),
+        };
+        Point(T::from(line), T::from(column))
+    }
+
+    /// the direction of a single step, `None` if `delta` is no such step
+    pub fn from_delta<T: From<i8> + PartialEq>(delta: Point<T>) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.delta::<T>() == delta)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(value: Direction) -> Self {
+        match value {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+impl From<Direction8> for (i32, i32) {
+    fn from(value: Direction8) -> Self {
+        value.delta::<i32>().into()
+    }
+}
+
+/// step into `rhs`, `None` if that leaves the positive coordinates
+impl Add<Direction8> for Point {
+    type Output = Option<Point>;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + <(i32, i32)>::from(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.reverse(), Direction::Right);
+        assert_eq!(Direction8::Up.turn_right(), Direction8::UpRight);
+        assert_eq!(Direction8::Up.turn_left(), Direction8::UpLeft);
+        assert_eq!(Direction8::DownLeft.reverse(), Direction8::UpRight);
+    }
+
+    #[test]
+    fn test_parse() {
+        for (chars, dir) in ["^NU", ">ER", "vSD", "<WL"].into_iter().zip(Direction::ALL) {
+            for c in chars.chars() {
+                assert_eq!(Direction::try_from(c), Ok(dir));
+            }
+            assert_eq!(dir.to_string(), chars[..1]);
+        }
+        assert!(Direction::try_from('x').is_err());
+    }
+
+    #[test]
+    fn test_delta() {
+        assert_eq!(Direction::Up.delta::<isize>(), Point(-1, 0));
+        assert_eq!(Direction8::DownLeft.delta::<i32>(), Point(1, -1));
+        assert_eq!(
+            Direction::from_delta(Point(0_i64, -1)),
+            Some(Direction::Left)
+        );
+        assert_eq!(Direction::from_delta(Point(1_i64, 1)), None);
+        assert_eq!(
+            Direction8::from_delta(Point(1_i64, 1)),
+            Some(Direction8::DownRight)
+        );
+        assert_eq!(Point(0, 3) + Direction::Up, None);
+        assert_eq!(Point(1, 3) + Direction8::UpLeft, Some(Point(0, 2)));
+    }
+}
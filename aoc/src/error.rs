@@ -0,0 +1,137 @@
+use nom::{error::Error, Err, IResult};
+use std::fmt::Display;
+
+/// error while parsing the puzzle input
+///
+/// Points to the position in the input where parsing failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// line number, starting at 1
+    pub line: usize,
+    /// column in chars, starting at 1
+    pub column: usize,
+    /// the complete line containing the error
+    pub snippet: String,
+    /// description of what should have been found
+    pub expected: String,
+}
+
+impl ParseError {
+    /// error at the start of `at` which has to be a subslice of `input`
+    ///
+    /// If `at` is not part of `input` the error points to the end of `input`.
+    pub fn new(input: &str, at: &str, expected: impl Display) -> Self {
+        let offset = (at.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(input.len());
+        Self::at_offset(input, offset, expected)
+    }
+
+    /// error at byte `offset` of `input`
+    pub fn at_offset(input: &str, offset: usize, expected: impl Display) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let line_end = input[offset..]
+            .find('\n')
+            .map(|idx| offset + idx)
+            .unwrap_or(input.len());
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            expected: expected.to_string(),
+        }
+    }
+
+    /// turn the error of a nom parser which was run on `input` into a [`ParseError`]
+    pub fn from_nom(input: &str, err: Err<Error<&str>>) -> Self {
+        match err {
+            Err::Incomplete(_) => Self::new(input, &input[input.len()..], "more input"),
+            Err::Error(e) | Err::Failure(e) => Self::new(input, e.input, e.code.description()),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let line_number = self.line.to_string();
+        let padding = " ".repeat(line_number.len());
+        writeln!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )?;
+        writeln!(f, "{line_number} | {}", self.snippet)?;
+        write!(f, "{padding} | {}^", " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// get the result of a nom parser which was run on `input`
+///
+/// Only trailing whitespace may be left over, everything else is reported as error.
+pub fn finish<'a, T>(input: &'a str, result: IResult<&'a str, T>) -> Result<T, ParseError> {
+    let (rest, value) = result.map_err(|e| ParseError::from_nom(input, e))?;
+    if rest.trim().is_empty() {
+        Ok(value)
+    } else {
+        Err(ParseError::new(input, rest.trim_start(), "end of input"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete, sequence::separated_pair};
+
+    #[test]
+    fn test_position() {
+        let input = "1,2\n3,x\n";
+        let e = ParseError::new(input, &input[6..], "a number");
+        assert_eq!(e.line, 2);
+        assert_eq!(e.column, 3);
+        assert_eq!(e.snippet, "3,x");
+        assert_eq!(
+            e.to_string(),
+            "line 2, column 3: expected a number\n2 | 3,x\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_position_at_end() {
+        let input = "12";
+        let e = ParseError::new(input, "not part of input", "more");
+        assert_eq!((e.line, e.column), (1, 3));
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let input = "12|x";
+        let result = separated_pair(
+            complete::u64::<&str, Error<&str>>,
+            complete::char('|'),
+            complete::u64,
+        )(input);
+        let e = finish(input, result).unwrap_err();
+        assert_eq!((e.line, e.column), (1, 4));
+        assert_eq!(e.expected, "Digit");
+    }
+
+    #[test]
+    fn test_finish_rest() {
+        let input = "12\n";
+        assert_eq!(
+            finish(input, complete::u64::<&str, Error<&str>>(input)),
+            Ok(12)
+        );
+
+        let input = "12\n x";
+        let e = finish(input, complete::u64::<&str, Error<&str>>(input)).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 2));
+        assert_eq!(e.expected, "end of input");
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{Part, Solution};
+use std::fmt::Display;
+
+/// solve a single part of `input` and compare it with `expected`
+///
+/// The parsed input is passed through `prepare` first, e.g. to use the smaller sizes of
+/// an example. Used by the tests generated with [`examples!`](crate::examples).
+pub fn check_example<S: Solution>(
+    input: &str,
+    prepare: fn(S::Input) -> S::Input,
+    part: Part,
+    expected: impl Display,
+) {
+    let parsed = match S::parse(input) {
+        Ok(parsed) => prepare(parsed),
+        Err(e) => panic!("could not parse example\n{e}"),
+    };
+    let answer = match part {
+        Part::One => S::part1(&parsed).to_string(),
+        Part::Two => S::part2(&parsed).to_string(),
+    };
+    assert_eq!(answer, expected.to_string(), "part {part}");
+}
+
+/// apply the `with` closure of an example, which gets its argument type from here
+#[doc(hidden)]
+pub fn prepare_example<T>(input: T, prepare: impl FnOnce(T) -> T) -> T {
+    prepare(input)
+}
+
+/// generate a test for every example input of a day
+///
+/// The files are relative to the crate of the day, every example gets its own module
+/// with a `part1` and `part2` test for the given answers. `with` changes the parsed
+/// input before solving, for days whose examples are smaller than the real puzzle.
+///
+/// ```ignore
+/// aoc::examples! {
+///     Day22 {
+///         example1: "example1.txt", part1 = 37327623;
+///         example2: "example2.txt", part2 = 23;
+///     }
+/// }
+///
+/// aoc::examples! {
+///     Day18 {
+///         example: "example.txt", with = |bytes| bytes.with_size(7).with_fallen(12), part1 = 22;
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    ($day:ty { $($name:ident: $file:literal $(, with = $with:expr)? $(, part1 = $part1:expr)? $(, part2 = $part2:expr)?;)* }) => {
+        #[cfg(test)]
+        mod examples {
+            $(
+                mod $name {
+                    #[allow(unused_imports)]
+                    use super::super::*;
+
+                    const INPUT: &str =
+                        include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $file));
+
+                    fn prepare(
+                        input: <$day as $crate::Solution>::Input,
+                    ) -> <$day as $crate::Solution>::Input {
+                        $(let input = $crate::prepare_example(input, $with);)?
+                        input
+                    }
+
+                    $(
+                        #[test]
+                        fn part1() {
+                            $crate::check_example::<$day>(INPUT, prepare, $crate::Part::One, $part1);
+                        }
+                    )?
+
+                    $(
+                        #[test]
+                        fn part2() {
+                            $crate::check_example::<$day>(INPUT, prepare, $crate::Part::Two, $part2);
+                        }
+                    )?
+                }
+            )*
+        }
+    };
+}
@@ -0,0 +1,201 @@
+use grid::Grid;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+};
+
+/// colour of a pixel as red, green and blue
+pub type Rgb = [u8; 3];
+
+/// write `grid` as binary PPM image, one pixel per cell coloured by `color`
+pub fn write_ppm<T>(
+    mut out: impl Write,
+    grid: &Grid<T>,
+    color: impl Fn(&T) -> Rgb,
+) -> io::Result<()> {
+    writeln!(out, "P6\n{} {}\n255", grid.cols(), grid.rows())?;
+    for row in grid.iter_rows() {
+        for cell in row {
+            out.write_all(&color(cell))?;
+        }
+    }
+    out.flush()
+}
+
+/// write `grid` as binary PGM image, one pixel per cell with brightness `gray`
+pub fn write_pgm<T>(
+    mut out: impl Write,
+    grid: &Grid<T>,
+    gray: impl Fn(&T) -> u8,
+) -> io::Result<()> {
+    writeln!(out, "P5\n{} {}\n255", grid.cols(), grid.rows())?;
+    for row in grid.iter_rows() {
+        for cell in row {
+            out.write_all(&[gray(cell)])?;
+        }
+    }
+    out.flush()
+}
+
+/// environment variable with the directory [`Frames::from_env`] writes to
+pub const FRAMES_ENV: &str = "AOC_FRAMES";
+
+/// numbered image files in a directory, e.g. one per step of a simulation
+///
+/// The frames are called `frame_00000.ppm`, `frame_00001.ppm` and so on, so they can be
+/// turned into an animation with `ffmpeg -i frame_%05d.ppm`. All frames of a sequence
+/// have the format of the first one, writing another format fails.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    next: usize,
+    extension: Option<&'static str>,
+}
+
+impl Frames {
+    /// start a sequence in `dir`, which is created if necessary
+    pub fn new(dir: impl AsRef<Path>) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self {
+            dir: dir.as_ref().to_owned(),
+            next: 0,
+            extension: None,
+        })
+    }
+
+    /// start a sequence in `name` below the directory in [`FRAMES_ENV`], if it is set
+    ///
+    /// Frames are only a debugging aid, so a directory which can't be created is
+    /// reported on stderr and gives `None` as well.
+    pub fn from_env(name: impl AsRef<Path>) -> Option<Self> {
+        let dir = PathBuf::from(std::env::var_os(FRAMES_ENV)?).join(name);
+        match Self::new(&dir) {
+            Ok(frames) => Some(frames),
+            Err(e) => {
+                eprintln!("could not create {}: {e}", dir.display());
+                None
+            }
+        }
+    }
+
+    /// write the next frame as PPM image, returns its path
+    pub fn ppm<T>(&mut self, grid: &Grid<T>, color: impl Fn(&T) -> Rgb) -> io::Result<PathBuf> {
+        let path = self.next_path("ppm")?;
+        write_ppm(BufWriter::new(File::create(&path)?), grid, color)?;
+        Ok(path)
+    }
+
+    /// write the next frame as PGM image, returns its path
+    pub fn pgm<T>(&mut self, grid: &Grid<T>, gray: impl Fn(&T) -> u8) -> io::Result<PathBuf> {
+        let path = self.next_path("pgm")?;
+        write_pgm(BufWriter::new(File::create(&path)?), grid, gray)?;
+        Ok(path)
+    }
+
+    /// write the next frame of an optional sequence as PPM image, see [`Frames::ppm`]
+    ///
+    /// Frames are only a debugging aid, so the first error is reported on stderr and
+    /// ends the sequence instead of the caller.
+    pub fn record_ppm<T>(frames: &mut Option<Self>, grid: &Grid<T>, color: impl Fn(&T) -> Rgb) {
+        if let Some(sequence) = frames {
+            let result = sequence.ppm(grid, color);
+            Self::stop_on_error(frames, result);
+        }
+    }
+
+    /// write the next frame of an optional sequence as PGM image, see [`Frames::record_ppm`]
+    pub fn record_pgm<T>(frames: &mut Option<Self>, grid: &Grid<T>, gray: impl Fn(&T) -> u8) {
+        if let Some(sequence) = frames {
+            let result = sequence.pgm(grid, gray);
+            Self::stop_on_error(frames, result);
+        }
+    }
+
+    /// number of frames written so far
+    pub fn len(&self) -> usize {
+        self.next
+    }
+
+    /// whether no frame was written yet
+    pub fn is_empty(&self) -> bool {
+        self.next == 0
+    }
+
+    fn stop_on_error(frames: &mut Option<Self>, result: io::Result<PathBuf>) {
+        if let Err(e) = result {
+            if let Some(sequence) = frames.take() {
+                eprintln!(
+                    "could not write a frame to {}, no further frames are written: {e}",
+                    sequence.dir.display()
+                );
+            }
+        }
+    }
+
+    fn next_path(&mut self, extension: &'static str) -> io::Result<PathBuf> {
+        if let Some(first) = self.extension.filter(|first| *first != extension) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("can't add a {extension} frame to a sequence of {first} frames"),
+            ));
+        }
+        self.extension = Some(extension);
+        let path = self.dir.join(format!("frame_{:05}.{extension}", self.next));
+        self.next += 1;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ppm() {
+        let grid = Grid::from_vec(vec![true, false], 2);
+        let mut out = Vec::new();
+        write_ppm(&mut out, &grid, |on| if *on { [255, 0, 0] } else { [0; 3] }).unwrap();
+        assert_eq!(out, b"P6\n2 1\n255\n\xff\x00\x00\x00\x00\x00");
+    }
+
+    #[test]
+    fn test_pgm() {
+        let grid = Grid::from_vec(vec![1_u8, 2, 3, 4], 1);
+        let mut out = Vec::new();
+        write_pgm(&mut out, &grid, |v| v * 10).unwrap();
+        assert_eq!(out, b"P5\n1 4\n255\n\x0a\x14\x1e\x28");
+    }
+
+    #[test]
+    fn test_frames() {
+        let dir = std::env::temp_dir().join(format!("aoc-frames-{}", std::process::id()));
+        let grid: Grid<u8> = Grid::new(2, 3);
+        let mut frames = Frames::new(&dir).unwrap();
+        frames.ppm(&grid, |v| [*v; 3]).unwrap();
+        let second = frames.ppm(&grid, |v| [*v; 3]).unwrap();
+        assert_eq!(frames.len(), 2);
+        assert_eq!(second, dir.join("frame_00001.ppm"));
+        assert!(dir.join("frame_00000.ppm").is_file());
+        assert_eq!(fs::read(second).unwrap().len(), "P6\n3 2\n255\n".len() + 18);
+        // a pgm would break `ffmpeg -i frame_%05d.ppm`
+        let mixed = frames.pgm(&grid, |v| *v).unwrap_err();
+        assert_eq!(mixed.kind(), io::ErrorKind::InvalidInput);
+        assert_eq!(frames.len(), 2);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_record_stops_on_error() {
+        let dir = std::env::temp_dir().join(format!("aoc-record-{}", std::process::id()));
+        let grid: Grid<u8> = Grid::new(1, 1);
+        let mut frames = Some(Frames::new(&dir).unwrap());
+        Frames::record_pgm(&mut frames, &grid, |v| *v);
+        assert_eq!(frames.as_ref().map(Frames::len), Some(1));
+        fs::remove_dir_all(&dir).unwrap();
+        Frames::record_pgm(&mut frames, &grid, |v| *v);
+        assert!(frames.is_none());
+        Frames::record_pgm(&mut frames, &grid, |v| *v);
+        assert!(!dir.exists());
+    }
+}
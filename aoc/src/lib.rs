@@ -1,6 +1,32 @@
+mod answer;
+mod bitgrid;
+mod direction;
+mod error;
+mod example;
+mod image;
+mod neighbours;
+mod parse;
+mod path;
 mod point;
-#[macro_use]
-extern crate impl_ops;
+mod region;
+mod render;
+mod search;
+mod solution;
+mod sparse;
 
+pub use answer::*;
+pub use bitgrid::*;
+pub use direction::*;
+pub use error::*;
+pub use example::*;
 pub use grid::*;
+pub use image::*;
+pub use neighbours::*;
+pub use parse::*;
+pub use path::*;
 pub use point::*;
+pub use region::*;
+pub use render::*;
+pub use search::*;
+pub use solution::*;
+pub use sparse::*;
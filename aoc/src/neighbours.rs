@@ -0,0 +1,190 @@
+use crate::{Direction8, Point};
+use grid::Grid;
+use std::slice;
+
+/// which of the surrounding cells count as neighbours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Adjacency {
+    /// up, right, down and left
+    Cardinal,
+    /// the four corners
+    Diagonal,
+    /// all eight surrounding cells
+    All,
+}
+
+impl Adjacency {
+    /// the directions of the neighbours, clockwise starting with up
+    pub fn directions(self) -> &'static [Direction8] {
+        use Direction8::*;
+        match self {
+            Adjacency::Cardinal => &[Up, Right, Down, Left],
+            Adjacency::Diagonal => &[UpRight, DownRight, DownLeft, UpLeft],
+            Adjacency::All => &Direction8::ALL,
+        }
+    }
+}
+
+/// iterator over the neighbours of a cell, see [`GridNeighbours`]
+#[derive(Debug, Clone)]
+pub struct Neighbours {
+    center: Point,
+    size: (usize, usize),
+    directions: slice::Iter<'static, Direction8>,
+    wrapping: bool,
+}
+
+impl Iterator for Neighbours {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for dir in self.directions.by_ref() {
+            let next = if self.wrapping {
+                Some(wrapping_step(self.size, self.center, *dir))
+            } else {
+                step(self.size, self.center, *dir)
+            };
+            if next.is_some() {
+                return next;
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.directions.len()))
+    }
+}
+
+fn step((rows, cols): (usize, usize), p: Point, dir: Direction8) -> Option<Point> {
+    let Point(line, column) = p;
+    let Point(line_delta, column_delta) = dir.delta::<isize>();
+    let line = line.checked_add_signed(line_delta).filter(|l| *l < rows)?;
+    let column = column
+        .checked_add_signed(column_delta)
+        .filter(|c| *c < cols)?;
+    Some(Point(line, column))
+}
+
+fn wrapping_step(size: (usize, usize), p: Point, dir: Direction8) -> Point {
+    wrapping_offset(size, p, dir.delta())
+}
+
+fn wrapping_offset((rows, cols): (usize, usize), p: Point, delta: Point<isize>) -> Point {
+    let wrap = |value: usize, delta: isize, len: usize| {
+        (value as isize + delta).rem_euclid(len as isize) as usize
+    };
+    Point(wrap(p.0, delta.0, rows), wrap(p.1, delta.1, cols))
+}
+
+/// neighbourhood of the cells of a grid
+///
+/// The iterators don't borrow the grid, so it can be changed while iterating.
+pub trait GridNeighbours {
+    /// number of rows and columns
+    fn dimensions(&self) -> (usize, usize);
+
+    /// the cell next to `p` in direction `dir`, if it is inside the grid
+    fn step(&self, p: Point, dir: impl Into<Direction8>) -> Option<Point> {
+        step(self.dimensions(), p, dir.into())
+    }
+
+    /// the cell next to `p` in direction `dir`, wrapping around the edges
+    fn wrapping_step(&self, p: Point, dir: impl Into<Direction8>) -> Point {
+        wrapping_step(self.dimensions(), p, dir.into())
+    }
+
+    /// the cell `delta` away from `p`, wrapping around the edges as often as needed
+    fn wrapping_offset(&self, p: Point, delta: Point<isize>) -> Point {
+        wrapping_offset(self.dimensions(), p, delta)
+    }
+
+    /// the neighbours of `p` inside the grid
+    fn neighbours(&self, p: Point, adjacency: Adjacency) -> Neighbours {
+        Neighbours {
+            center: p,
+            size: self.dimensions(),
+            directions: adjacency.directions().iter(),
+            wrapping: false,
+        }
+    }
+
+    /// the neighbours of `p` on a torus, leaving on one edge enters on the opposite one
+    ///
+    /// Always yields every direction of `adjacency`, in grids thinner than three cells
+    /// the same neighbour may be returned more than once.
+    fn wrapping_neighbours(&self, p: Point, adjacency: Adjacency) -> Neighbours {
+        Neighbours {
+            wrapping: true,
+            ..self.neighbours(p, adjacency)
+        }
+    }
+}
+
+impl<T> GridNeighbours for Grid<T> {
+    fn dimensions(&self) -> (usize, usize) {
+        self.size()
+    }
+}
+
+/// just the number of rows and columns, for areas which are not stored as grid
+impl GridNeighbours for (usize, usize) {
+    fn dimensions(&self) -> (usize, usize) {
+        *self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Direction;
+
+    #[test]
+    fn test_neighbours() {
+        let grid: Grid<u8> = Grid::new(3, 4);
+        let cardinal: Vec<_> = grid.neighbours(Point(0, 0), Adjacency::Cardinal).collect();
+        assert_eq!(cardinal, [Point(0, 1), Point(1, 0)]);
+        let diagonal: Vec<_> = grid.neighbours(Point(2, 3), Adjacency::Diagonal).collect();
+        assert_eq!(diagonal, [Point(1, 2)]);
+        assert_eq!(grid.neighbours(Point(1, 1), Adjacency::All).count(), 8);
+        assert_eq!(grid.neighbours(Point(1, 0), Adjacency::All).count(), 5);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid: Grid<u8> = Grid::new(3, 4);
+        let cardinal: Vec<_> = grid
+            .wrapping_neighbours(Point(0, 0), Adjacency::Cardinal)
+            .collect();
+        assert_eq!(
+            cardinal,
+            [Point(2, 0), Point(0, 1), Point(1, 0), Point(0, 3)]
+        );
+        assert_eq!(
+            grid.wrapping_step(Point(2, 3), Direction8::DownRight),
+            Point(0, 0)
+        );
+    }
+
+    #[test]
+    fn test_wrapping_offset() {
+        let size = (3, 4);
+        assert_eq!(
+            size.wrapping_offset(Point(1, 1), Point(7, -10)),
+            Point(2, 3)
+        );
+        assert_eq!(size.wrapping_offset(Point(2, 3), Point(-3, 4)), Point(2, 3));
+    }
+
+    #[test]
+    fn test_step() {
+        let grid: Grid<u8> = Grid::new(3, 4);
+        assert_eq!(grid.step(Point(0, 0), Direction::Up), None);
+        assert_eq!(grid.step(Point(0, 0), Direction::Right), Some(Point(0, 1)));
+        assert_eq!(grid.step(Point(2, 0), Direction8::DownRight), None);
+        assert_eq!(
+            grid.step(Point(2, 3), Direction8::UpLeft),
+            Some(Point(1, 2))
+        );
+    }
+}
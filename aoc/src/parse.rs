@@ -0,0 +1,150 @@
+use crate::{ParseError, Point};
+use grid::Grid;
+use std::{collections::HashMap, fmt::Display};
+
+/// positions of the marker chars found while parsing a grid
+pub type Markers = HashMap<char, Point>;
+
+/// parse a rectangular grid of chars, one row per line
+pub trait ParseChars: Sized {
+    /// parse every char into a cell via `TryFrom<char>`
+    ///
+    /// All rows must have the same number of chars.
+    fn parse_chars(input: &str) -> Result<Self, ParseError>;
+
+    /// like [`ParseChars::parse_chars`], but pulls out the positions of `markers`
+    ///
+    /// Every marker has to occur exactly once. Its cell is set to the default value
+    /// instead of being converted.
+    fn parse_chars_with_markers(
+        input: &str,
+        markers: &[char],
+    ) -> Result<(Self, Markers), ParseError>;
+}
+
+impl<T> ParseChars for Grid<T>
+where
+    T: TryFrom<char> + Default,
+    T::Error: Display,
+{
+    fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Self::parse_chars_with_markers(input, &[]).map(|(grid, _)| grid)
+    }
+
+    fn parse_chars_with_markers(
+        input: &str,
+        markers: &[char],
+    ) -> Result<(Self, Markers), ParseError> {
+        let width = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new(input, input, "at least one row"))?
+            .chars()
+            .count();
+        let mut cells = Vec::with_capacity(input.len());
+        let mut found = Markers::new();
+        for (line_idx, line) in input.lines().enumerate() {
+            let mut columns = 0;
+            for (col_idx, (idx, c)) in line.char_indices().enumerate() {
+                let at = &line[idx..];
+                if col_idx == width {
+                    return Err(ParseError::new(input, at, format!("{width} columns")));
+                }
+                if markers.contains(&c) {
+                    if found.insert(c, Point(line_idx, col_idx)).is_some() {
+                        return Err(ParseError::new(input, at, format!("only one `{c}`")));
+                    }
+                    cells.push(T::default());
+                } else {
+                    let cell = T::try_from(c)
+                        .map_err(|e| ParseError::new(input, at, format!("a grid cell ({e})")))?;
+                    cells.push(cell);
+                }
+                columns += 1;
+            }
+            if columns < width {
+                let end_of_line = &line[line.len()..];
+                return Err(ParseError::new(
+                    input,
+                    end_of_line,
+                    format!("{width} columns"),
+                ));
+            }
+        }
+        if let Some(missing) = markers.iter().find(|m| !found.contains_key(m)) {
+            let end_of_input = &input[input.len()..];
+            return Err(ParseError::new(
+                input,
+                end_of_input,
+                format!("a `{missing}`"),
+            ));
+        }
+        Ok((Grid::from_vec(cells, width), found))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Default, PartialEq)]
+    enum Cell {
+        #[default]
+        Empty,
+        Wall,
+    }
+
+    impl TryFrom<char> for Cell {
+        type Error = String;
+
+        fn try_from(value: char) -> Result<Self, Self::Error> {
+            match value {
+                '.' => Ok(Self::Empty),
+                '#' => Ok(Self::Wall),
+                c => Err(format!("unknown cell {c}")),
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let grid: Grid<Cell> = Grid::parse_chars("#.\n.#\n..\n").unwrap();
+        assert_eq!(grid.size(), (3, 2));
+        assert_eq!(grid[(0, 0)], Cell::Wall);
+        assert_eq!(grid[(1, 0)], Cell::Empty);
+        assert_eq!(grid[(1, 1)], Cell::Wall);
+
+        let chars: Grid<char> = Grid::parse_chars("ab\r\ncd").unwrap();
+        assert_eq!(chars.iter().collect::<String>(), "abcd");
+    }
+
+    #[test]
+    fn test_markers() {
+        let (grid, markers) =
+            Grid::<Cell>::parse_chars_with_markers("#S.\n.#E\n", &['S', 'E']).unwrap();
+        assert_eq!(markers[&'S'], Point(0, 1));
+        assert_eq!(markers[&'E'], Point(1, 2));
+        assert_eq!(grid[markers[&'S']], Cell::Empty);
+
+        let missing = Grid::<Cell>::parse_chars_with_markers("#S.\n", &['S', 'E']).unwrap_err();
+        assert_eq!(missing.expected, "a `E`");
+        let twice = Grid::<Cell>::parse_chars_with_markers("SS\n", &['S']).unwrap_err();
+        assert_eq!((twice.line, twice.column), (1, 2));
+    }
+
+    #[test]
+    fn test_errors() {
+        let short = Grid::<Cell>::parse_chars("...\n..\n").unwrap_err();
+        assert_eq!((short.line, short.column), (2, 3));
+        assert_eq!(short.expected, "3 columns");
+
+        let long = Grid::<Cell>::parse_chars("..\n...\n").unwrap_err();
+        assert_eq!((long.line, long.column), (2, 3));
+
+        let unknown = Grid::<Cell>::parse_chars("..\n.x\n").unwrap_err();
+        assert_eq!((unknown.line, unknown.column), (2, 2));
+        assert_eq!(unknown.expected, "a grid cell (unknown cell x)");
+
+        assert!(Grid::<Cell>::parse_chars("").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{Direction, GridNeighbours, Point};
+use pathfinding::prelude::{astar, astar_bag};
+use std::{collections::HashSet, ops::Index};
+
+/// search state, the heading is only tracked if turns cost something
+type State = (Point, Option<Direction>);
+
+/// weighted shortest paths over a grid
+///
+/// `cost` gives the cost of entering a cell, `None` for cells which can't be entered.
+/// Works on every grid which can be indexed by [`Point`], e.g. `Grid<T>` or
+/// [`BitGrid`](crate::BitGrid).
+pub struct PathFinder<'a, G, C> {
+    grid: &'a G,
+    cost: C,
+    turns: Option<(Direction, usize)>,
+    min_cost: usize,
+}
+
+impl<'a, G, C> PathFinder<'a, G, C>
+where
+    G: GridNeighbours + Index<Point>,
+    C: Fn(&G::Output) -> Option<usize>,
+{
+    pub fn new(grid: &'a G, cost: C) -> Self {
+        Self {
+            grid,
+            cost,
+            turns: None,
+            min_cost: 0,
+        }
+    }
+
+    /// start `facing` a direction, every 90 degree turn costs `turn_cost`
+    pub fn with_turns(mut self, facing: Direction, turn_cost: usize) -> Self {
+        self.turns = Some((facing, turn_cost));
+        self
+    }
+
+    /// lower bound for the cost of entering any passable cell
+    ///
+    /// Lets the search head towards the goal instead of expanding in every direction.
+    pub fn with_min_cost(mut self, min_cost: usize) -> Self {
+        self.min_cost = min_cost;
+        self
+    }
+
+    /// one of the cheapest paths from `start` to `goal` including both, and its cost
+    pub fn shortest_path(&self, start: Point, goal: Point) -> Option<(Vec<Point>, usize)> {
+        let (states, cost) = astar(
+            &self.start(start),
+            |state| self.successors(*state),
+            |(p, _)| p.manhattan(goal) * self.min_cost,
+            |(p, _)| *p == goal,
+        )?;
+        Some((states.into_iter().map(|(p, _)| p).collect(), cost))
+    }
+
+    /// all cheapest paths from `start` to `goal`, and their cost
+    pub fn all_shortest_paths(
+        &self,
+        start: Point,
+        goal: Point,
+    ) -> Option<(Vec<Vec<Point>>, usize)> {
+        let (solutions, cost) = astar_bag(
+            &self.start(start),
+            |state| self.successors(*state),
+            |(p, _)| p.manhattan(goal) * self.min_cost,
+            |(p, _)| *p == goal,
+        )?;
+        let paths = solutions
+            .map(|states| states.into_iter().map(|(p, _)| p).collect())
+            .collect();
+        Some((paths, cost))
+    }
+
+    /// the cells which are part of at least one cheapest path, and the cost of the paths
+    pub fn cells_on_shortest_paths(
+        &self,
+        start: Point,
+        goal: Point,
+    ) -> Option<(HashSet<Point>, usize)> {
+        let (paths, cost) = self.all_shortest_paths(start, goal)?;
+        Some((paths.into_iter().flatten().collect(), cost))
+    }
+
+    fn start(&self, start: Point) -> State {
+        (start, self.turns.map(|(facing, _)| facing))
+    }
+
+    fn successors(&self, (p, facing): State) -> impl Iterator<Item = (State, usize)> + '_ {
+        Direction::ALL.into_iter().filter_map(move |dir| {
+            let next = self.grid.step(p, dir)?;
+            let cost = (self.cost)(&self.grid[next])?;
+            match (facing, self.turns) {
+                (Some(facing), Some((_, turn_cost))) => {
+                    let turns = if dir == facing {
+                        0
+                    } else if dir == facing.reverse() {
+                        2
+                    } else {
+                        1
+                    };
+                    Some(((next, Some(dir)), cost + turns * turn_cost))
+                }
+                _ => Some(((next, None), cost)),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BitGrid;
+    use grid::Grid;
+
+    fn maze() -> Grid<char> {
+        Grid::from_vec("...#.#....".chars().collect(), 5)
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let maze = maze();
+        let finder = PathFinder::new(&maze, |c| (*c == '.').then_some(1));
+        let (path, cost) = finder.shortest_path(Point(0, 0), Point(0, 4)).unwrap();
+        assert_eq!(cost, 6);
+        assert_eq!(path.len(), 7);
+        assert_eq!(path[0], Point(0, 0));
+        assert_eq!(path[6], Point(0, 4));
+        assert_eq!(finder.shortest_path(Point(0, 0), Point(0, 3)), None);
+
+        let walls = BitGrid::from_grid(&maze, |c| *c == '#');
+        let finder = PathFinder::new(&walls, |wall| (!wall).then_some(1)).with_min_cost(1);
+        assert_eq!(finder.shortest_path(Point(0, 0), Point(0, 4)).unwrap().1, 6);
+    }
+
+    #[test]
+    fn test_turns() {
+        let open: Grid<char> = Grid::init(2, 3, '.');
+        let finder = PathFinder::new(&open, |_| Some(1)).with_turns(Direction::Right, 1000);
+        assert_eq!(finder.shortest_path(Point(0, 0), Point(0, 2)).unwrap().1, 2);
+        assert_eq!(
+            finder.shortest_path(Point(0, 0), Point(1, 2)).unwrap().1,
+            1003
+        );
+        assert_eq!(
+            finder.shortest_path(Point(0, 2), Point(0, 0)).unwrap().1,
+            2002
+        );
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let open: Grid<char> = Grid::init(2, 3, '.');
+        let finder = PathFinder::new(&open, |_| Some(1));
+        let (paths, cost) = finder.all_shortest_paths(Point(0, 0), Point(1, 2)).unwrap();
+        assert_eq!((paths.len(), cost), (3, 3));
+
+        let ring = Grid::from_vec("....#....".chars().collect(), 3);
+        let turning =
+            PathFinder::new(&ring, |c| (*c == '.').then_some(1)).with_turns(Direction::Right, 1000);
+        let (cells, cost) = turning
+            .cells_on_shortest_paths(Point(1, 0), Point(1, 2))
+            .unwrap();
+        assert_eq!(cost, 3004);
+        assert_eq!(cells.len(), 8);
+    }
+}
@@ -1,33 +1,140 @@
 use grid::Grid;
-use std::ops::{self, Add, Index, IndexMut, Sub};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point(pub usize, pub usize);
+/// position or offset on a grid as `Point(line, column)`
+///
+/// `Point<usize>` is the default and indexes directly into a [`Grid`].
+/// Signed points are used for offsets and for positions which may leave the grid.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T = usize>(pub T, pub T);
 
-impl Point {
-    pub fn new(a: usize, b: usize) -> Self {
+impl<T> Point<T> {
+    pub const fn new(a: T, b: T) -> Self {
         Self(a, b)
     }
 }
 
-impl From<(usize, usize)> for Point {
-    fn from(value: (usize, usize)) -> Self {
+impl<T: Copy + Ord + Add<Output = T> + Sub<Output = T>> Point<T> {
+    /// number of orthogonal steps between both points
+    pub fn manhattan(self, other: Self) -> T {
+        let (a, b) = self.abs_diff(other);
+        a + b
+    }
+
+    /// number of steps between both points if diagonal steps are allowed
+    pub fn chebyshev(self, other: Self) -> T {
+        let (a, b) = self.abs_diff(other);
+        a.max(b)
+    }
+
+    fn abs_diff(self, other: Self) -> (T, T) {
+        let diff = |a: T, b: T| if a > b { a - b } else { b - a };
+        (diff(self.0, other.0), diff(self.1, other.1))
+    }
+}
+
+impl<T: Copy> Point<T> {
+    /// convert both coordinates, `None` if one of them does not fit into `U`
+    pub fn try_cast<U: TryFrom<T>>(self) -> Option<Point<U>> {
+        Some(Point(self.0.try_into().ok()?, self.1.try_into().ok()?))
+    }
+
+    /// the index of this position in `grid`, `None` if it lies outside
+    pub fn grid_index<G>(self, grid: &Grid<G>) -> Option<Point>
+    where
+        usize: TryFrom<T>,
+    {
+        self.try_cast::<usize>()
+            .filter(|p| p.0 < grid.rows() && p.1 < grid.cols())
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from(value: (T, T)) -> Self {
         Point(value.0, value.1)
     }
 }
 
-impl From<&(usize, usize)> for Point {
-    fn from(value: &(usize, usize)) -> Self {
+impl<T: Copy> From<&(T, T)> for Point<T> {
+    fn from(value: &(T, T)) -> Self {
         Point(value.0, value.1)
     }
 }
 
-impl From<Point> for (usize, usize) {
-    fn from(value: Point) -> Self {
+impl<T> From<Point<T>> for (T, T) {
+    fn from(value: Point<T>) -> Self {
+        (value.0, value.1)
+    }
+}
+
+impl<T: Copy> From<&Point<T>> for (T, T) {
+    fn from(value: &Point<T>) -> Self {
         (value.0, value.1)
     }
 }
 
+/// implement a component wise operator for all combinations of owned and borrowed points
+macro_rules! component_op {
+    ($op:ident, $fn:ident, $assign_op:ident, $assign_fn:ident) => {
+        impl<T: $op<Output = T>> $op for Point<T> {
+            type Output = Point<T>;
+
+            fn $fn(self, rhs: Self) -> Self::Output {
+                Point(self.0.$fn(rhs.0), self.1.$fn(rhs.1))
+            }
+        }
+
+        impl<T: Copy + $op<Output = T>> $op<&Point<T>> for Point<T> {
+            type Output = Point<T>;
+
+            fn $fn(self, rhs: &Point<T>) -> Self::Output {
+                self.$fn(*rhs)
+            }
+        }
+
+        impl<T: Copy + $op<Output = T>> $op<Point<T>> for &Point<T> {
+            type Output = Point<T>;
+
+            fn $fn(self, rhs: Point<T>) -> Self::Output {
+                (*self).$fn(rhs)
+            }
+        }
+
+        impl<T: Copy + $op<Output = T>> $op<&Point<T>> for &Point<T> {
+            type Output = Point<T>;
+
+            fn $fn(self, rhs: &Point<T>) -> Self::Output {
+                (*self).$fn(*rhs)
+            }
+        }
+
+        impl<T: Copy + $op<Output = T>> $assign_op for Point<T> {
+            fn $assign_fn(&mut self, rhs: Self) {
+                *self = (*self).$fn(rhs);
+            }
+        }
+    };
+}
+
+component_op!(Add, add, AddAssign, add_assign);
+component_op!(Sub, sub, SubAssign, sub_assign);
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Point(self.0 * rhs, self.1 * rhs)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Self::Output {
+        Point(-self.0, -self.1)
+    }
+}
+
 impl<T> Index<Point> for Grid<T> {
     type Output = T;
 
@@ -56,12 +163,6 @@ impl<T> IndexMut<&Point> for Grid<T> {
     }
 }
 
-impl From<&Point> for (usize, usize) {
-    fn from(value: &Point) -> Self {
-        (value.0, value.1)
-    }
-}
-
 pub fn checked_idx<T: Copy>(p: Point, g: &Grid<T>) -> Option<T> {
     let (line, col) = p.into();
     if line >= g.rows() || col >= g.cols() {
@@ -145,17 +246,39 @@ impl Sub<&(i32, i32)> for &Point {
     }
 }
 
-impl_op_ex!(+ |a: &Point, b: &Point| -> Point { Point(a.0 + b.0, a.1 + b.1)});
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-/// get the valid cardinal neighbours for a given position in the grid
-///
-/// checks wether the neighbours are in the grid or not
-/// Will return an empty vector if there are no neighbours
-pub fn get_cardinal_neighbours<T>(grid: &Grid<T>, p: &Point) -> Vec<Point> {
-    const CARDINAL_DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-    CARDINAL_DIRECTIONS
-        .iter()
-        .flat_map(|dir| p + *dir)
-        .filter(|p| grid.rows() > p.0 && grid.cols() > p.1)
-        .collect()
+    #[test]
+    fn test_arithmetic() {
+        let a = Point(1_isize, -2);
+        let b = Point(-3, 4);
+        assert_eq!(a + b, Point(-2, 2));
+        assert_eq!(a - b, Point(4, -6));
+        assert_eq!(a * 3, Point(3, -6));
+        assert_eq!(-a, Point(-1, 2));
+        let mut c = a;
+        c += b;
+        c -= a;
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = Point(1_usize, 7);
+        let b = Point(4, 5);
+        assert_eq!(a.manhattan(b), 5);
+        assert_eq!(a.chebyshev(b), 3);
+        assert_eq!(Point(-1_i32, 2).manhattan(Point(2, -2)), 7);
+    }
+
+    #[test]
+    fn test_grid_index() {
+        let grid: Grid<u8> = Grid::new(2, 3);
+        assert_eq!(Point(1_isize, 2).grid_index(&grid), Some(Point(1, 2)));
+        assert_eq!(Point(-1_isize, 2).grid_index(&grid), None);
+        assert_eq!(Point(2_i32, 0).grid_index(&grid), None);
+        assert_eq!(Point(1_usize, 3).grid_index(&grid), None);
+    }
 }
@@ -0,0 +1,204 @@
+use crate::{Adjacency, Components, Direction8, GridNeighbours, Point};
+use grid::Grid;
+use std::collections::{HashSet, VecDeque};
+
+/// a set of cells, e.g. a connected component of a grid
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Region {
+    cells: HashSet<Point>,
+}
+
+impl Region {
+    /// whether `p` is part of the region
+    pub fn contains(&self, p: Point) -> bool {
+        self.cells.contains(&p)
+    }
+
+    /// the cells of the region in no particular order
+    pub fn cells(&self) -> impl Iterator<Item = Point> + '_ {
+        self.cells.iter().copied()
+    }
+
+    /// number of cells
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// number of cell edges between the region and the outside
+    pub fn perimeter(&self) -> usize {
+        self.cells
+            .iter()
+            .flat_map(|p| Adjacency::Cardinal.directions().iter().map(|dir| *p + *dir))
+            .filter(|next| !self.has(*next))
+            .count()
+    }
+
+    /// number of straight sides of the outline, including the sides of holes
+    ///
+    /// A polygon has as many sides as corners, so the corners are counted.
+    pub fn sides(&self) -> usize {
+        use Direction8::*;
+        const CORNERS: [(Direction8, Direction8, Direction8); 4] = [
+            (Up, Left, UpLeft),
+            (Up, Right, UpRight),
+            (Down, Right, DownRight),
+            (Down, Left, DownLeft),
+        ];
+        let mut corners = 0;
+        for p in self.cells.iter() {
+            for (a, b, diagonal) in CORNERS {
+                let (a, b, diagonal) =
+                    (self.has(*p + a), self.has(*p + b), self.has(*p + diagonal));
+                // convex corners have no neighbour on both sides, concave corners have
+                // both neighbours but miss the one in between
+                if (!a && !b) || (a && b && !diagonal) {
+                    corners += 1;
+                }
+            }
+        }
+        corners
+    }
+
+    /// top left and bottom right corner of the smallest rectangle containing the region
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let top = self.cells.iter().map(|p| p.0).min()?;
+        let left = self.cells.iter().map(|p| p.1).min()?;
+        let bottom = self.cells.iter().map(|p| p.0).max()?;
+        let right = self.cells.iter().map(|p| p.1).max()?;
+        Some((Point(top, left), Point(bottom, right)))
+    }
+
+    /// the cells of the region which have a cardinal neighbour outside of it
+    pub fn outline(&self) -> Region {
+        self.cells
+            .iter()
+            .filter(|p| {
+                Adjacency::Cardinal
+                    .directions()
+                    .iter()
+                    .any(|dir| !self.has(**p + *dir))
+            })
+            .copied()
+            .collect()
+    }
+
+    /// enclosed areas of cells which are not part of the region
+    ///
+    /// Cells are only enclosed if they can't reach the outside, not even diagonally.
+    pub fn holes(&self) -> Vec<Region> {
+        let Some((Point(top, left), Point(bottom, right))) = self.bounding_box() else {
+            return Vec::new();
+        };
+        // the region on a grid with a free border, so the outside is connected
+        let mut free: Grid<bool> = Grid::init(bottom - top + 3, right - left + 3, true);
+        for p in self.cells.iter() {
+            free[(p.0 - top + 1, p.1 - left + 1)] = false;
+        }
+        let outside = fill(&free, Point(0, 0));
+        let mut seen: HashSet<Point> = outside.into_iter().collect();
+        let mut holes = Vec::new();
+        for ((line, column), is_free) in free.indexed_iter() {
+            let p = Point(line, column);
+            if *is_free && !seen.contains(&p) {
+                let hole = fill(&free, p);
+                seen.extend(hole.iter().copied());
+                holes.push(
+                    hole.into_iter()
+                        .map(|p| Point(p.0 + top - 1, p.1 + left - 1))
+                        .collect(),
+                );
+            }
+        }
+        holes
+    }
+
+    fn has(&self, p: Option<Point>) -> bool {
+        p.is_some_and(|p| self.cells.contains(&p))
+    }
+}
+
+/// all free cells reachable from `start` in all eight directions
+fn fill(free: &Grid<bool>, start: Point) -> Vec<Point> {
+    let mut found = vec![start];
+    let mut seen: Grid<bool> = Grid::new(free.rows(), free.cols());
+    seen[start] = true;
+    let mut queue = VecDeque::from([start]);
+    while let Some(current) = queue.pop_front() {
+        for next in free.neighbours(current, Adjacency::All) {
+            if free[next] && !seen[next] {
+                seen[next] = true;
+                found.push(next);
+                queue.push_back(next);
+            }
+        }
+    }
+    found
+}
+
+impl FromIterator<Point> for Region {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Components {
+    /// the cells with label `label` as [`Region`]
+    pub fn region(&self, label: usize) -> Region {
+        self.labels()
+            .indexed_iter()
+            .filter(|(_, l)| **l == label)
+            .map(|((line, column), _)| Point(line, column))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn region(rows: &[&str]) -> Region {
+        rows.iter()
+            .enumerate()
+            .flat_map(|(line, row)| {
+                row.char_indices()
+                    .filter(|(_, c)| *c == '#')
+                    .map(move |(column, _)| Point(line, column))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_measures() {
+        let square = region(&["##", "##"]);
+        assert_eq!(square.area(), 4);
+        assert_eq!(square.perimeter(), 8);
+        assert_eq!(square.sides(), 4);
+
+        let l_shape = region(&["#.", "##"]);
+        assert_eq!(l_shape.perimeter(), 8);
+        assert_eq!(l_shape.sides(), 6);
+        assert_eq!(l_shape.bounding_box(), Some((Point(0, 0), Point(1, 1))));
+        assert_eq!(Region::default().bounding_box(), None);
+    }
+
+    #[test]
+    fn test_holes() {
+        let ring = region(&["....", ".###", ".#.#", ".###"]);
+        assert_eq!(ring.perimeter(), 16);
+        assert_eq!(ring.sides(), 8);
+        assert_eq!(ring.holes(), [Region::from_iter([Point(2, 2)])]);
+        assert_eq!(ring.outline().area(), 8);
+
+        let open = region(&["##.", "#.#", "###"]);
+        assert!(open.holes().is_empty());
+    }
+
+    #[test]
+    fn test_components_region() {
+        let grid = Grid::from_vec("aab".chars().collect(), 3);
+        let components = crate::components(&grid, |a, b| a == b);
+        assert_eq!(components.region(1), Region::from_iter([Point(0, 2)]));
+    }
+}
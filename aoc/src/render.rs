@@ -0,0 +1,172 @@
+use crate::Point;
+use grid::Grid;
+use std::{
+    collections::HashSet,
+    fmt::{self, Display},
+};
+
+/// ANSI colour of an overlay
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+}
+
+impl Color {
+    /// the SGR code of the foreground colour
+    fn code(self) -> u8 {
+        match self {
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Overlay {
+    points: HashSet<Point>,
+    glyph: char,
+    color: Option<Color>,
+}
+
+/// text rendering of a grid, one line per row
+///
+/// Overlays replace the cells at their points with a glyph, later overlays are drawn
+/// on top of earlier ones.
+#[derive(Clone)]
+pub struct Render<'a, T, F = fn(&T) -> String> {
+    grid: &'a Grid<T>,
+    cell: F,
+    overlays: Vec<Overlay>,
+    ansi: bool,
+}
+
+/// render every cell with its [`Display`] implementation
+pub fn render<T: Display>(grid: &Grid<T>) -> Render<'_, T> {
+    render_with(grid, T::to_string)
+}
+
+/// render every cell with `cell`, e.g. for cells without [`Display`] implementation
+pub fn render_with<T, F>(grid: &Grid<T>, cell: F) -> Render<'_, T, F>
+where
+    F: Fn(&T) -> String,
+{
+    Render {
+        grid,
+        cell,
+        overlays: Vec::new(),
+        ansi: true,
+    }
+}
+
+impl<T, F> Render<'_, T, F>
+where
+    F: Fn(&T) -> String,
+{
+    /// draw `glyph` at all `points`
+    pub fn overlay(self, points: impl IntoIterator<Item = Point>, glyph: char) -> Self {
+        self.add(points, glyph, None)
+    }
+
+    /// draw `glyph` in `color` at all `points`
+    pub fn colored(
+        self,
+        points: impl IntoIterator<Item = Point>,
+        glyph: char,
+        color: Color,
+    ) -> Self {
+        self.add(points, glyph, Some(color))
+    }
+
+    /// enable or disable ANSI colours, they are enabled by default
+    pub fn ansi(mut self, enabled: bool) -> Self {
+        self.ansi = enabled;
+        self
+    }
+
+    fn add(
+        mut self,
+        points: impl IntoIterator<Item = Point>,
+        glyph: char,
+        color: Option<Color>,
+    ) -> Self {
+        self.overlays.push(Overlay {
+            points: points.into_iter().collect(),
+            glyph,
+            color,
+        });
+        self
+    }
+}
+
+impl<T, F> Display for Render<'_, T, F>
+where
+    F: Fn(&T) -> String,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (line, row) in self.grid.iter_rows().enumerate() {
+            for (column, cell) in row.enumerate() {
+                let overlay = self
+                    .overlays
+                    .iter()
+                    .rev()
+                    .find(|overlay| overlay.points.contains(&Point(line, column)));
+                match overlay {
+                    Some(Overlay {
+                        glyph,
+                        color: Some(color),
+                        ..
+                    }) if self.ansi => write!(f, "\x1b[{}m{glyph}\x1b[0m", color.code())?,
+                    Some(overlay) => write!(f, "{}", overlay.glyph)?,
+                    None => write!(f, "{}", (self.cell)(cell))?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_vec("#...#.".chars().collect(), 3)
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(render(&grid()).to_string(), "#..\n.#.\n");
+        let numbers = Grid::from_vec(vec![Some(1), None], 2);
+        let rendered = render_with(&numbers, |n| {
+            n.map_or(" .".to_owned(), |n| format!("{n:2}"))
+        });
+        assert_eq!(rendered.to_string(), " 1 .\n");
+    }
+
+    #[test]
+    fn test_overlay() {
+        let grid = grid();
+        let rendered = render(&grid)
+            .overlay([Point(0, 1), Point(0, 2)], 'O')
+            .overlay([Point(0, 2)], 'X');
+        assert_eq!(rendered.to_string(), "#OX\n.#.\n");
+    }
+
+    #[test]
+    fn test_colored() {
+        let grid = grid();
+        let rendered = render(&grid).colored([Point(1, 0)], 'O', Color::Red);
+        assert_eq!(rendered.to_string(), "#..\n\x1b[31mO\x1b[0m#.\n");
+        assert_eq!(rendered.ansi(false).to_string(), "#..\nO#.\n");
+    }
+}
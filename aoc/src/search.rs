@@ -0,0 +1,184 @@
+use crate::{Adjacency, GridNeighbours, Point};
+use grid::Grid;
+use std::collections::VecDeque;
+
+/// breadth first search from `start` over cardinal neighbours
+///
+/// A step from one cell to the next is only taken if `can_move(from, to)` holds.
+/// Returns the number of steps to every cell, `None` for unreachable cells.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    start: Point,
+    can_move: impl Fn(Point, Point) -> bool,
+) -> Grid<Option<u32>> {
+    multi_bfs(grid, [start], can_move)
+}
+
+/// breadth first search from several cells at once
+///
+/// Every cell gets the distance to the nearest start.
+pub fn multi_bfs<T>(
+    grid: &Grid<T>,
+    starts: impl IntoIterator<Item = Point>,
+    can_move: impl Fn(Point, Point) -> bool,
+) -> Grid<Option<u32>> {
+    let mut distances: Grid<Option<u32>> = Grid::new(grid.rows(), grid.cols());
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if distances[start].is_none() {
+            distances[start] = Some(0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(current) = queue.pop_front() {
+        let distance = distances[current].expect("queued cells have a distance");
+        for next in grid.neighbours(current, Adjacency::Cardinal) {
+            if distances[next].is_none() && can_move(current, next) {
+                distances[next] = Some(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// all cells connected to `start` over cardinal neighbours for which `include` holds
+///
+/// The cells are returned in the order they are found, starting with `start`.
+/// Nothing is found if `start` itself is not included.
+pub fn flood_fill<T>(grid: &Grid<T>, start: Point, include: impl Fn(&T) -> bool) -> Vec<Point> {
+    if !include(&grid[start]) {
+        return Vec::new();
+    }
+    let mut seen: Grid<bool> = Grid::new(grid.rows(), grid.cols());
+    seen[start] = true;
+    let mut found = vec![start];
+    let mut next_idx = 0;
+    while let Some(&current) = found.get(next_idx) {
+        next_idx += 1;
+        for next in grid.neighbours(current, Adjacency::Cardinal) {
+            if !seen[next] && include(&grid[next]) {
+                seen[next] = true;
+                found.push(next);
+            }
+        }
+    }
+    found
+}
+
+/// connected components of a grid, see [`components`]
+#[derive(Debug, Clone)]
+pub struct Components {
+    labels: Grid<usize>,
+    count: usize,
+}
+
+impl Components {
+    /// the label of the component containing `p`
+    pub fn label(&self, p: Point) -> usize {
+        self.labels[p]
+    }
+
+    /// the label of every cell, labels are numbered from 0 in reading order
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    /// number of components
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// whether the grid had no cells at all
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// the cells of every component, indexed by label
+    pub fn regions(&self) -> Vec<Vec<Point>> {
+        let mut regions = vec![Vec::new(); self.count];
+        for ((line, column), label) in self.labels.indexed_iter() {
+            regions[*label].push(Point(line, column));
+        }
+        regions
+    }
+}
+
+/// label the connected components of `grid`
+///
+/// Two cardinal neighbours belong to the same component if `connected` holds for
+/// their values, e.g. `|a, b| a == b` for areas of equal cells.
+pub fn components<T>(grid: &Grid<T>, connected: impl Fn(&T, &T) -> bool) -> Components {
+    let mut labels: Grid<Option<usize>> = Grid::new(grid.rows(), grid.cols());
+    let mut count = 0;
+    let mut queue = VecDeque::new();
+    for line in 0..grid.rows() {
+        for column in 0..grid.cols() {
+            let start = Point(line, column);
+            if labels[start].is_some() {
+                continue;
+            }
+            labels[start] = Some(count);
+            queue.push_back(start);
+            while let Some(current) = queue.pop_front() {
+                for next in grid.neighbours(current, Adjacency::Cardinal) {
+                    if labels[next].is_none() && connected(&grid[current], &grid[next]) {
+                        labels[next] = Some(count);
+                        queue.push_back(next);
+                    }
+                }
+            }
+            count += 1;
+        }
+    }
+    let labels = Grid::from_vec(
+        labels
+            .into_vec()
+            .into_iter()
+            .map(|label| label.expect("every cell is labelled"))
+            .collect(),
+        grid.cols(),
+    );
+    Components { labels, count }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> Grid<char> {
+        Grid::from_vec("..#.\n.##.\n....".replace('\n', "").chars().collect(), 4)
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = grid();
+        let distances = bfs(&grid, Point(0, 0), |_, to| grid[to] == '.');
+        assert_eq!(distances[(0, 0)], Some(0));
+        assert_eq!(distances[(0, 3)], Some(7));
+        assert_eq!(distances[(1, 1)], None);
+
+        let distances = multi_bfs(&grid, [Point(0, 0), Point(0, 3)], |_, to| grid[to] == '.');
+        assert_eq!(distances[(2, 3)], Some(2));
+        assert_eq!(distances[(2, 1)], Some(3));
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = grid();
+        let walls = flood_fill(&grid, Point(1, 1), |c| *c == '#');
+        assert_eq!(walls, [Point(1, 1), Point(1, 2), Point(0, 2)]);
+        assert!(flood_fill(&grid, Point(0, 0), |c| *c == '#').is_empty());
+    }
+
+    #[test]
+    fn test_components() {
+        let grid = grid();
+        let components = components(&grid, |a, b| a == b);
+        assert_eq!(components.len(), 2);
+        assert_eq!(components.label(Point(0, 0)), 0);
+        assert_eq!(components.label(Point(0, 2)), 1);
+        assert_eq!(components.label(Point(0, 3)), 0);
+        assert_eq!(components.regions()[1].len(), 3);
+    }
+}
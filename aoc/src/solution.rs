@@ -0,0 +1,132 @@
+use crate::{Answer, ParseError};
+use serde_json::Value;
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// every puzzle consists of two parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(value: Part) -> Self {
+        match value {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            x => Err(format!("there is no part {x}")),
+        }
+    }
+}
+
+/// common interface every day implements
+///
+/// The input is parsed once and then handed to both parts.
+pub trait Solution {
+    /// parsed puzzle input shared by both parts
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> impl Answer;
+
+    fn part2(input: &Self::Input) -> impl Answer;
+
+    /// forget everything memoized by earlier runs, so the next run starts cold
+    fn reset() {}
+}
+
+/// answer of a single part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    pub part: Part,
+    pub answer: String,
+    /// time spent solving the part, without parsing
+    pub duration: Duration,
+    /// see [`Answer::details`]
+    pub details: Option<Value>,
+}
+
+/// everything a single run of a [`Solver`] produced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// time spent in [`Solution::parse`]
+    pub parse: Duration,
+    pub outputs: Vec<Output>,
+}
+
+/// object safe version of [`Solution`]
+///
+/// Every [`Solution`] implements this so the runner can keep all days in one table.
+pub trait Solver {
+    /// parse `input` and solve the requested parts
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError>;
+
+    /// see [`Solution::reset`]
+    fn reset(&self);
+}
+
+impl<S: Solution> Solver for S {
+    fn solve(&self, input: &str, parts: &[Part]) -> Result<Report, ParseError> {
+        let now = Instant::now();
+        let parsed = S::parse(input)?;
+        let parse = now.elapsed();
+
+        let outputs = parts
+            .iter()
+            .map(|part| {
+                let now = Instant::now();
+                let (answer, duration, details) = match part {
+                    Part::One => {
+                        let answer = S::part1(&parsed);
+                        let duration = now.elapsed();
+                        (answer.to_string(), duration, answer.details())
+                    }
+                    Part::Two => {
+                        let answer = S::part2(&parsed);
+                        let duration = now.elapsed();
+                        (answer.to_string(), duration, answer.details())
+                    }
+                };
+                Output {
+                    part: *part,
+                    answer,
+                    duration,
+                    details,
+                }
+            })
+            .collect();
+        Ok(Report { parse, outputs })
+    }
+
+    fn reset(&self) {
+        S::reset()
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{render_with, Adjacency, Point};
+use grid::Grid;
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// grid without fixed size, only the cells which were set are stored
+///
+/// Cells are addressed by signed points, so the grid can grow in every direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point<isize>, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// an empty grid
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// the value at `p`, if it was set
+    pub fn get(&self, p: Point<isize>) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    /// mutable access to the value at `p`, if it was set
+    pub fn get_mut(&mut self, p: Point<isize>) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    /// set the value at `p`, returns the previous value
+    pub fn insert(&mut self, p: Point<isize>, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    /// unset `p`, returns the previous value
+    pub fn remove(&mut self, p: Point<isize>) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    /// the cell at `p` for in-place manipulation
+    pub fn entry(&mut self, p: Point<isize>) -> Entry<'_, Point<isize>, T> {
+        self.cells.entry(p)
+    }
+
+    /// whether `p` was set
+    pub fn contains(&self, p: Point<isize>) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    /// number of set cells
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// whether no cell is set
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// the set cells in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.cells.iter().map(|(p, value)| (*p, value))
+    }
+
+    /// the neighbours of `p`, no matter whether they are set
+    pub fn neighbours(
+        &self,
+        p: Point<isize>,
+        adjacency: Adjacency,
+    ) -> impl Iterator<Item = Point<isize>> {
+        adjacency
+            .directions()
+            .iter()
+            .map(move |dir| p + dir.delta::<isize>())
+    }
+
+    /// top left and bottom right corner of the smallest rectangle containing all set cells
+    pub fn bounding_box(&self) -> Option<(Point<isize>, Point<isize>)> {
+        let top = self.cells.keys().map(|p| p.0).min()?;
+        let left = self.cells.keys().map(|p| p.1).min()?;
+        let bottom = self.cells.keys().map(|p| p.0).max()?;
+        let right = self.cells.keys().map(|p| p.1).max()?;
+        Some((Point(top, left), Point(bottom, right)))
+    }
+
+    /// dense copy of the bounding box
+    ///
+    /// Also returns the top left corner, which is `Point(0, 0)` in the dense grid.
+    pub fn to_grid(&self) -> (Grid<Option<&T>>, Point<isize>) {
+        let Some((top_left, bottom_right)) = self.bounding_box() else {
+            return (Grid::new(0, 0), Point(0, 0));
+        };
+        let Point(rows, cols) = bottom_right - top_left + Point(1, 1);
+        let mut grid = Grid::new(rows as usize, cols as usize);
+        for (p, value) in self.iter() {
+            let Point(line, column) = p - top_left;
+            grid[(line as usize, column as usize)] = Some(value);
+        }
+        (grid, top_left)
+    }
+}
+
+impl<T> Index<Point<isize>> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<isize>) -> &Self::Output {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{p:?} is not set in the sparse grid"))
+    }
+}
+
+impl<T> IndexMut<Point<isize>> for SparseGrid<T> {
+    fn index_mut(&mut self, p: Point<isize>) -> &mut Self::Output {
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{p:?} is not set in the sparse grid"))
+    }
+}
+
+impl<T> FromIterator<(Point<isize>, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point<isize>, T)>>(iter: I) -> Self {
+        Self {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T> Extend<(Point<isize>, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point<isize>, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+/// renders the bounding box, cells which are not set are shown as `.`
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (grid, _) = self.to_grid();
+        let rendered = render_with(&grid, |cell| {
+            cell.map_or_else(|| ".".to_owned(), |value| value.to_string())
+        });
+        write!(f, "{rendered}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid = SparseGrid::new();
+        grid.insert(Point(-1, 2), 'a');
+        grid.insert(Point(1, -1), 'b');
+        *grid.entry(Point(0, 0)).or_insert('x') = 'c';
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid[Point(0, 0)], 'c');
+        assert_eq!(grid.get(Point(5, 5)), None);
+        assert_eq!(grid.bounding_box(), Some((Point(-1, -1), Point(1, 2))));
+        assert_eq!(grid.to_string(), "...a\n.c..\nb...\n");
+        assert_eq!(grid.remove(Point(-1, 2)), Some('a'));
+        assert_eq!(grid.to_string(), ".c\nb.\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid: SparseGrid<()> = SparseGrid::new();
+        let neighbours: Vec<_> = grid.neighbours(Point(0, 0), Adjacency::Cardinal).collect();
+        assert_eq!(
+            neighbours,
+            [Point(-1, 0), Point(0, 1), Point(1, 0), Point(0, -1)]
+        );
+        assert_eq!(grid.neighbours(Point(0, 0), Adjacency::All).count(), 8);
+    }
+}
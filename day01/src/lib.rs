@@ -0,0 +1,116 @@
+use aoc::{Answer, ParseError, Solution};
+use std::collections::HashMap;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Part1, Part2);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok((Part1::parse(input)?, Part2::parse(input)?))
+    }
+
+    fn part1((part1, _): &Self::Input) -> impl Answer {
+        part1.clone().solve()
+    }
+
+    fn part2((_, part2): &Self::Input) -> impl Answer {
+        part2.solve()
+    }
+}
+
+/// parse the first two numbers of a line of `input`
+fn parse_line(input: &str, line: &str) -> Result<(usize, usize), ParseError> {
+    let tmp: Vec<usize> = line
+        .split_whitespace()
+        .map(|x| {
+            x.parse::<usize>()
+                .map_err(|_| ParseError::new(input, x, "a number"))
+        })
+        .collect::<Result<_, _>>()?;
+    if tmp.len() < 2 {
+        return Err(ParseError::new(input, &line[line.len()..], "two numbers"));
+    }
+    Ok((tmp[0], tmp[1]))
+}
+
+#[derive(Debug, Clone)]
+pub struct Part1 {
+    left: Vec<usize>,
+    right: Vec<usize>,
+}
+
+impl Part1 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut result = Self {
+            left: vec![],
+            right: vec![],
+        };
+        for line in input.lines() {
+            let (left, right) = parse_line(input, line)?;
+            result.left.push(left);
+            result.right.push(right);
+        }
+        Ok(result)
+    }
+
+    fn sort(&mut self) {
+        self.right.sort();
+        self.left.sort();
+    }
+
+    fn get_differences(&self) -> Vec<usize> {
+        self.left
+            .iter()
+            .zip(&self.right)
+            .map(|(l, r)| l.abs_diff(*r))
+            .collect()
+    }
+
+    fn solve(&mut self) -> usize {
+        self.sort();
+        self.get_differences().iter().sum()
+    }
+}
+
+#[derive(Debug)]
+pub struct Part2 {
+    left: Vec<usize>,
+    right: HashMap<usize, usize>,
+}
+
+impl Part2 {
+    fn parse(input: &str) -> Result<Self, ParseError> {
+        let mut result = Self {
+            left: vec![],
+            right: HashMap::default(),
+        };
+        for line in input.lines() {
+            let (left, right) = parse_line(input, line)?;
+            result.left.push(left);
+            result
+                .right
+                .entry(right)
+                .and_modify(|e| *e += 1)
+                .or_insert(1);
+        }
+        Ok(result)
+    }
+
+    fn get_differences(&self) -> Vec<usize> {
+        self.left
+            .iter()
+            .map(|l| l * self.right.get(l).unwrap_or(&0))
+            .collect()
+    }
+
+    fn solve(&self) -> usize {
+        self.get_differences().iter().sum()
+    }
+}
+
+aoc::examples! {
+    Day01 {
+        example: "example.txt", part1 = 11, part2 = 31;
+    }
+}
@@ -0,0 +1,316 @@
+use aoc::{Answer, ParseError, Solution};
+use std::ops::RangeInclusive;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Report>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_reports(input)
+    }
+
+    fn part1(input: &Self::Input) -> impl Answer {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> impl Answer {
+        part2(input)
+    }
+}
+
+fn part1(reports: &[Report]) -> usize {
+    reports.iter().filter(|report| is_safe(report)).count()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Increasing,
+    Decreasing,
+    NotSet,
+}
+
+pub type Report = Vec<i32>;
+
+/// what makes a report safe
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// allowed difference between adjacent levels
+    steps: RangeInclusive<u32>,
+    /// number of levels which may be removed
+    removals: usize,
+}
+
+/// the outcome of checking a report against a [`Rule`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Safety {
+    Safe,
+    /// safe after removing the levels at the given indices
+    Fixable {
+        first_violation: usize,
+        remove: Vec<usize>,
+    },
+    Unsafe {
+        first_violation: usize,
+    },
+}
+
+impl Rule {
+    /// steps of 1 to 3, nothing may be removed
+    pub const STRICT: Rule = Rule::new(1..=3, 0);
+    /// steps of 1 to 3, the problem dampener may remove one level
+    pub const DAMPENED: Rule = Rule::new(1..=3, 1);
+
+    pub const fn new(steps: RangeInclusive<u32>, removals: usize) -> Self {
+        Self { steps, removals }
+    }
+
+    /// check `report`, takes O(n * k) for n levels and k allowed removals
+    pub fn check(&self, report: &[i32]) -> Safety {
+        let Some(first_violation) = self.first_violation(report) else {
+            return Safety::Safe;
+        };
+        match self.fewest_removals(report) {
+            Some(remove) => Safety::Fixable {
+                first_violation,
+                remove,
+            },
+            None => Safety::Unsafe { first_violation },
+        }
+    }
+
+    /// index of the first level which breaks the rule if nothing is removed
+    fn first_violation(&self, report: &[i32]) -> Option<usize> {
+        let mut direction = Direction::NotSet;
+        for (idx, pair) in report.windows(2).enumerate() {
+            match compare_number_and_direction(pair[0], pair[1], direction, &self.steps) {
+                Ok(val) => direction = val,
+                Err(_) => return Some(idx + 1),
+            }
+        }
+        None
+    }
+
+    /// the fewest levels to remove so the report becomes safe, if not more than allowed
+    fn fewest_removals(&self, report: &[i32]) -> Option<Vec<usize>> {
+        let increasing = self.kept_levels(report, Direction::Increasing);
+        let decreasing = self.kept_levels(report, Direction::Decreasing);
+        let kept = match (increasing, decreasing) {
+            (Some(inc), Some(dec)) => Some(if dec.len() > inc.len() { dec } else { inc }),
+            (inc, dec) => inc.or(dec),
+        }?;
+        let mut is_kept = vec![false; report.len()];
+        for idx in kept {
+            is_kept[idx] = true;
+        }
+        Some((0..report.len()).filter(|idx| !is_kept[*idx]).collect())
+    }
+
+    /// the longest chain of levels going in `direction` which misses at most `removals`
+    /// levels
+    ///
+    /// Every kept level can only follow one of the `removals + 1` levels before it, so
+    /// each level is only compared to a few predecessors.
+    fn kept_levels(&self, report: &[i32], direction: Direction) -> Option<Vec<usize>> {
+        let k = self.removals;
+        // for every level: the fewest removals for a valid chain ending at it, and the
+        // previous level of that chain
+        let mut best: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(report.len());
+        for idx in 0..report.len() {
+            // drop every level before this one
+            let mut candidate = (idx <= k).then_some((idx, None));
+            for prev in idx.saturating_sub(k + 1)..idx {
+                let Some((removed, _)) = best[prev] else {
+                    continue;
+                };
+                let removed = removed + idx - prev - 1;
+                let valid =
+                    compare_number_and_direction(report[prev], report[idx], direction, &self.steps)
+                        .is_ok();
+                if valid && removed <= k && candidate.is_none_or(|(c, _)| removed < c) {
+                    candidate = Some((removed, Some(prev)));
+                }
+            }
+            best.push(candidate);
+        }
+        // drop every level after the last one of the chain
+        let (mut last, _) = best
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, b)| b.map(|(removed, _)| (idx, removed + report.len() - idx - 1)))
+            .filter(|(_, removed)| *removed <= k)
+            .min_by_key(|(_, removed)| *removed)?;
+        let mut kept = vec![last];
+        while let Some((_, Some(prev))) = best[last] {
+            kept.push(prev);
+            last = prev;
+        }
+        kept.reverse();
+        Some(kept)
+    }
+}
+
+fn parse_reports(input: &str) -> Result<Vec<Report>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|n| {
+                    n.parse::<i32>()
+                        .map_err(|_| ParseError::new(input, n, "a number"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+fn part2(reports: &[Report]) -> usize {
+    reports
+        .iter()
+        .filter(|report| !matches!(Rule::DAMPENED.check(report), Safety::Unsafe { .. }))
+        .count()
+}
+
+fn is_safe(report: &Report) -> bool {
+    Rule::STRICT.check(report) == Safety::Safe
+}
+
+fn compare_number_and_direction(
+    prev: i32,
+    curr: i32,
+    curr_dir: Direction,
+    steps: &RangeInclusive<u32>,
+) -> Result<Direction, ()> {
+    if !steps.contains(&prev.abs_diff(curr)) {
+        return Err(());
+    }
+    use std::cmp::Ordering;
+    match (curr.cmp(&prev), curr_dir) {
+        (Ordering::Less, Direction::Increasing) => Err(()),
+        (Ordering::Less, _) => Ok(Direction::Decreasing),
+        (Ordering::Equal, _) => Err(()),
+        (Ordering::Greater, Direction::Decreasing) => Err(()),
+        (Ordering::Greater, _) => Ok(Direction::Increasing),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 2, Direction::NotSet, Ok(Direction::Increasing))]
+    #[case(1, 2, Direction::Increasing, Ok(Direction::Increasing))]
+    #[case(1, 2, Direction::Decreasing, Err(()))]
+    #[case(2, 1, Direction::Decreasing, Ok(Direction::Decreasing))]
+    #[case(2, 1, Direction::NotSet, Ok(Direction::Decreasing))]
+    #[case(2, 2, Direction::NotSet, Err(()))]
+    #[case(2, 2, Direction::Increasing, Err(()))]
+    #[case(2, 2, Direction::Decreasing, Err(()))]
+    #[case(2, 7, Direction::Decreasing, Err(()))]
+    #[case(7, 2, Direction::Decreasing, Err(()))]
+    #[case(7, 6, Direction::NotSet, Ok(Direction::Decreasing))]
+    #[case(6, 4, Direction::Decreasing, Ok(Direction::Decreasing))]
+    #[case(4, 2, Direction::Decreasing, Ok(Direction::Decreasing))]
+    #[case(2, 1, Direction::Decreasing, Ok(Direction::Decreasing))]
+    fn test_compare_number_and_direction(
+        #[case] prev: i32,
+        #[case] curr: i32,
+        #[case] curr_dir: Direction,
+        #[case] expected: Result<Direction, ()>,
+    ) {
+        assert_eq!(
+            compare_number_and_direction(prev, curr, curr_dir, &(1..=3)),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(vec![7,6,4,2,1],true)]
+    #[case(vec![1,2,7,8,9],false)]
+    #[case(vec![9,7,6,2,1],false)]
+    #[case(vec![1,3,2,4,5],false)]
+    #[case(vec![8,6,4,4,1],false)]
+    #[case(vec![1,3,6,7,9],true)]
+    #[case(vec![1,2,4,5],true)]
+    fn test_is_safe(#[case] report: Report, #[case] expected: bool) {
+        assert_eq!(is_safe(&report), expected)
+    }
+
+    #[rstest]
+    #[case(vec![7, 6, 4, 2, 1], Safety::Safe)]
+    #[case(vec![1, 2, 7, 8, 9], Safety::Unsafe { first_violation: 2 })]
+    #[case(vec![1, 3, 2, 4, 5], Safety::Fixable { first_violation: 2, remove: vec![2] })]
+    #[case(vec![8, 6, 4, 4, 1], Safety::Fixable { first_violation: 3, remove: vec![3] })]
+    #[case(vec![9, 1, 2, 3], Safety::Fixable { first_violation: 1, remove: vec![0] })]
+    #[case(vec![1, 2, 3, 9], Safety::Fixable { first_violation: 3, remove: vec![3] })]
+    fn test_check(#[case] report: Report, #[case] expected: Safety) {
+        assert_eq!(Rule::DAMPENED.check(&report), expected)
+    }
+
+    #[test]
+    fn test_check_configurable() {
+        let rule = Rule::new(2..=4, 2);
+        assert_eq!(rule.check(&[1, 3, 7, 11]), Safety::Safe);
+        assert_eq!(
+            rule.check(&[1, 3, 4, 4, 5, 7]),
+            Safety::Fixable {
+                first_violation: 2,
+                remove: vec![2, 3]
+            }
+        );
+        assert_eq!(
+            rule.check(&[1, 2, 3, 4, 5, 6]),
+            Safety::Unsafe { first_violation: 1 }
+        );
+    }
+
+    #[test]
+    fn test_check_matches_brute_force() {
+        // every removal of up to two levels from small pseudo random reports
+        let mut seed = 17u32;
+        for _ in 0..500 {
+            let report: Report = (0..7)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    (seed >> 16) as i32 % 9
+                })
+                .collect();
+            for k in 0..=2 {
+                let rule = Rule::new(1..=3, k);
+                let brute_force = (0..report.len())
+                    .flat_map(|a| (a..report.len()).map(move |b| (a, b)))
+                    .filter(|(a, b)| if a == b { 1 } else { 2 } <= k)
+                    .any(|(a, b)| {
+                        let kept: Report = (0..report.len())
+                            .filter(|idx| *idx != a && *idx != b)
+                            .map(|idx| report[idx])
+                            .collect();
+                        is_safe(&kept)
+                    })
+                    || is_safe(&report);
+                let safety = rule.check(&report);
+                assert_eq!(
+                    !matches!(safety, Safety::Unsafe { .. }),
+                    brute_force,
+                    "{report:?} with {k} removals"
+                );
+                if let Safety::Fixable { remove, .. } = safety {
+                    assert!(remove.len() <= k);
+                    let kept: Report = (0..report.len())
+                        .filter(|idx| !remove.contains(idx))
+                        .map(|idx| report[idx])
+                        .collect();
+                    assert!(is_safe(&kept), "{report:?} without {remove:?}");
+                }
+            }
+        }
+    }
+}
+
+aoc::examples! {
+    Day02 {
+        example: "example.txt", part1 = 2, part2 = 4;
+    }
+}
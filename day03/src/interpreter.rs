@@ -0,0 +1,211 @@
+use crate::Spanned;
+
+/// state the instructions work on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Machine {
+    pub result: i64,
+    /// number of open `don't()` scopes, instructions only fire if there is none
+    pub disabled: usize,
+}
+
+impl Machine {
+    pub fn enabled(&self) -> bool {
+        self.disabled == 0
+    }
+}
+
+type Semantics = Box<dyn Fn(&mut Machine, &[i32])>;
+
+/// a named instruction with a fixed number of arguments
+struct Instruction {
+    name: &'static str,
+    arity: usize,
+    /// control instructions also run while the machine is disabled
+    control: bool,
+    semantics: Semantics,
+}
+
+/// the instructions a [`Scanner`](crate::Scanner) looks for and the interpreter runs
+#[derive(Default)]
+pub struct InstructionSet {
+    instructions: Vec<Instruction>,
+}
+
+impl InstructionSet {
+    /// an instruction set without any instruction
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `mul(a,b)` with `do()` and `don't()` switching it on and off
+    pub fn standard() -> Self {
+        Self::new()
+            .instruction("mul", 2, multiply)
+            .control("do", 0, |machine, _| machine.disabled = 0)
+            .control("don't", 0, |machine, _| machine.disabled = 1)
+    }
+
+    /// like [`InstructionSet::standard`], but every `do()` only closes the innermost `don't()`
+    pub fn scoped() -> Self {
+        Self::new()
+            .instruction("mul", 2, multiply)
+            .control("do", 0, |machine, _| {
+                machine.disabled = machine.disabled.saturating_sub(1)
+            })
+            .control("don't", 0, |machine, _| machine.disabled += 1)
+    }
+
+    /// register an instruction which only fires while the machine is enabled
+    ///
+    /// Replaces an instruction with the same name.
+    pub fn instruction(
+        self,
+        name: &'static str,
+        arity: usize,
+        semantics: impl Fn(&mut Machine, &[i32]) + 'static,
+    ) -> Self {
+        self.register(name, arity, false, Box::new(semantics))
+    }
+
+    /// register an instruction which always fires, e.g. to enable or disable the machine
+    pub fn control(
+        self,
+        name: &'static str,
+        arity: usize,
+        semantics: impl Fn(&mut Machine, &[i32]) + 'static,
+    ) -> Self {
+        self.register(name, arity, true, Box::new(semantics))
+    }
+
+    /// the names and arities of all instructions
+    pub fn signatures(&self) -> impl Iterator<Item = (&'static str, usize)> + '_ {
+        self.instructions
+            .iter()
+            .map(|instruction| (instruction.name, instruction.arity))
+    }
+
+    /// run `tokens` on a fresh machine
+    ///
+    /// Tokens of unknown instructions or with the wrong number of arguments are skipped.
+    pub fn run<'t>(&self, tokens: &'t [Spanned]) -> Execution<'t> {
+        let mut machine = Machine::default();
+        let mut trace = Vec::with_capacity(tokens.len());
+        for spanned in tokens {
+            let instruction = self
+                .get(spanned.token.name)
+                .filter(|instruction| instruction.arity == spanned.token.args.len());
+            let fired = match instruction {
+                Some(instruction) if instruction.control || machine.enabled() => {
+                    (instruction.semantics)(&mut machine, &spanned.token.args);
+                    true
+                }
+                _ => false,
+            };
+            trace.push(Step {
+                token: spanned,
+                fired,
+            });
+        }
+        Execution { machine, trace }
+    }
+
+    fn get(&self, name: &str) -> Option<&Instruction> {
+        self.instructions
+            .iter()
+            .find(|instruction| instruction.name == name)
+    }
+
+    fn register(
+        mut self,
+        name: &'static str,
+        arity: usize,
+        control: bool,
+        semantics: Semantics,
+    ) -> Self {
+        self.instructions
+            .retain(|instruction| instruction.name != name);
+        self.instructions.push(Instruction {
+            name,
+            arity,
+            control,
+            semantics,
+        });
+        self
+    }
+}
+
+/// semantics of `mul(a,b)`
+pub fn multiply(machine: &mut Machine, args: &[i32]) {
+    machine.result += i64::from(args[0]) * i64::from(args[1]);
+}
+
+/// a token and whether it fired
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<'t> {
+    pub token: &'t Spanned,
+    pub fired: bool,
+}
+
+/// the final machine and the trace of every token
+#[derive(Debug, Clone)]
+pub struct Execution<'t> {
+    pub machine: Machine,
+    pub trace: Vec<Step<'t>>,
+}
+
+impl Execution<'_> {
+    /// the tokens which were skipped
+    pub fn skipped(&self) -> impl Iterator<Item = &Spanned> + '_ {
+        self.trace
+            .iter()
+            .filter(|step| !step.fired)
+            .map(|step| step.token)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scan;
+
+    #[test]
+    fn test_standard_and_scoped() {
+        let input = "mul(1,2)don't()mul(3,4)don't()do()mul(5,6)do()mul(7,8)";
+        let tokens: Vec<_> = scan(input, &InstructionSet::standard()).tokens().collect();
+        assert_eq!(
+            InstructionSet::standard().run(&tokens).machine.result,
+            2 + 30 + 56
+        );
+        assert_eq!(InstructionSet::scoped().run(&tokens).machine.result, 2 + 56);
+    }
+
+    #[test]
+    fn test_custom_instructions() {
+        let set = InstructionSet::standard()
+            .instruction("add", 2, |machine, args| {
+                machine.result += i64::from(args[0] + args[1])
+            })
+            .instruction("neg", 1, |machine, args| {
+                machine.result -= i64::from(args[0])
+            });
+        let input = "add(1,2)neg(10)don't()add(5,5)neg(1,2)do()mul(2,3)";
+        let tokens: Vec<_> = scan(input, &set).tokens().collect();
+        let execution = set.run(&tokens);
+        assert_eq!(execution.machine.result, 3 - 10 + 6);
+        let fired: Vec<_> = execution.trace.iter().map(|step| step.fired).collect();
+        assert_eq!(fired, [true, true, true, false, true, true]);
+        assert_eq!(execution.skipped().next().map(|s| s.offset), Some(22));
+    }
+
+    #[test]
+    fn test_unknown_instructions_are_skipped() {
+        let tokens: Vec<_> = scan("mul(2,3)do()", &InstructionSet::standard())
+            .tokens()
+            .collect();
+        let execution = InstructionSet::new()
+            .instruction("mul", 2, multiply)
+            .run(&tokens);
+        assert_eq!(execution.machine.result, 6);
+        assert_eq!(execution.skipped().count(), 1);
+    }
+}
@@ -0,0 +1,34 @@
+use aoc::{Answer, ParseError, Solution};
+
+mod interpreter;
+mod scanner;
+
+pub use interpreter::*;
+pub use scanner::*;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<Spanned>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(scan(input, &InstructionSet::standard()).tokens().collect())
+    }
+
+    fn part1(tokens: &Self::Input) -> impl Answer {
+        // without `do()` and `don't()` every multiplication fires
+        let set = InstructionSet::new().instruction("mul", 2, multiply);
+        set.run(tokens).machine.result
+    }
+
+    fn part2(tokens: &Self::Input) -> impl Answer {
+        InstructionSet::standard().run(tokens).machine.result
+    }
+}
+
+aoc::examples! {
+    Day03 {
+        example1: "example1.txt", part1 = 161;
+        example2: "example2.txt", part2 = 48;
+    }
+}
@@ -1,7 +0,0 @@
-mod part1;
-mod part2;
-
-fn main() {
-    part1::run();
-    part2::run();
-}
@@ -0,0 +1,261 @@
+use crate::InstructionSet;
+use aoc::ParseError;
+use std::fmt::Display;
+
+/// a call of an instruction found in the corrupted memory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub name: &'static str,
+    pub args: Vec<i32>,
+}
+
+impl Token {
+    pub fn new(name: &'static str, args: impl Into<Vec<i32>>) -> Self {
+        Self {
+            name,
+            args: args.into(),
+        }
+    }
+}
+
+/// a token and the byte offset it starts at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spanned {
+    pub offset: usize,
+    pub token: Token,
+}
+
+/// text which starts like an instruction but isn't one, e.g. `mul(4*`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NearMiss<'a> {
+    pub offset: usize,
+    /// the text up to and including the first unexpected char
+    pub text: &'a str,
+    pub expected: &'static str,
+}
+
+impl NearMiss<'_> {
+    /// the near miss as error pointing at the unexpected char
+    pub fn to_error(&self, input: &str) -> ParseError {
+        let last = self.text.chars().next_back().map_or(0, char::len_utf8);
+        ParseError::at_offset(input, self.offset + self.text.len() - last, self.expected)
+    }
+}
+
+impl Display for NearMiss<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "offset {}: `{}` expected {}",
+            self.offset, self.text, self.expected
+        )
+    }
+}
+
+/// scans corrupted memory for the instructions of an [`InstructionSet`]
+///
+/// Yields every token as `Ok` and every near miss as `Err`, both in input order.
+/// Scanning resumes one char after a near miss, so `mulmul(1,2)` still finds the
+/// second instruction.
+pub struct Scanner<'a> {
+    input: &'a str,
+    pos: usize,
+    /// name and arity of every instruction, longest name first
+    signatures: Vec<(&'static str, usize)>,
+}
+
+/// scan `input` for the instructions of `set` and near misses
+pub fn scan<'a>(input: &'a str, set: &InstructionSet) -> Scanner<'a> {
+    let mut signatures: Vec<_> = set.signatures().collect();
+    signatures.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+    Scanner {
+        input,
+        pos: 0,
+        signatures,
+    }
+}
+
+impl<'a> Scanner<'a> {
+    /// only the valid tokens
+    pub fn tokens(self) -> impl Iterator<Item = Spanned> + 'a {
+        self.filter_map(Result::ok)
+    }
+
+    /// only the near misses
+    pub fn near_misses(self) -> impl Iterator<Item = NearMiss<'a>> {
+        self.filter_map(Result::err)
+    }
+
+    /// the instruction at `offset`, or what was expected instead and where
+    ///
+    /// If several names match, the longest call wins, and if none of them is a valid call
+    /// the one which got furthest is reported.
+    fn instruction(&self, offset: usize) -> Result<(Token, usize), (usize, &'static str)> {
+        let mut furthest = (offset, "an instruction");
+        for (name, arity) in self.signatures.iter() {
+            let mut cursor = Cursor {
+                input: self.input,
+                pos: offset,
+            };
+            if !cursor.eat(name) {
+                continue;
+            }
+            match cursor.call(*arity) {
+                Ok(args) => return Ok((Token::new(name, args), cursor.pos)),
+                Err(failure) if failure.0 > furthest.0 => furthest = failure,
+                Err(_) => {}
+            }
+        }
+        Err(furthest)
+    }
+}
+
+impl<'a> Iterator for Scanner<'a> {
+    type Item = Result<Spanned, NearMiss<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(c) = self.input[self.pos..].chars().next() {
+            let offset = self.pos;
+            match self.instruction(offset) {
+                Ok((token, end)) => {
+                    self.pos = end;
+                    return Some(Ok(Spanned { offset, token }));
+                }
+                Err((at, expected)) => {
+                    self.pos = offset + c.len_utf8();
+                    // text which doesn't even start with a name is just noise
+                    if at == offset {
+                        continue;
+                    }
+                    let end = self.input[at..]
+                        .chars()
+                        .next()
+                        .map_or(at, |c| at + c.len_utf8());
+                    return Some(Err(NearMiss {
+                        offset,
+                        text: &self.input[offset..end],
+                        expected,
+                    }));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// position while reading a single instruction
+struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn eat(&mut self, s: &str) -> bool {
+        let found = self.input[self.pos..].starts_with(s);
+        if found {
+            self.pos += s.len();
+        }
+        found
+    }
+
+    fn expect(&mut self, s: &str, expected: &'static str) -> Result<(), (usize, &'static str)> {
+        if self.eat(s) {
+            Ok(())
+        } else {
+            Err((self.pos, expected))
+        }
+    }
+
+    /// the arguments of a call with `arity` arguments, e.g. `(1,2)`
+    fn call(&mut self, arity: usize) -> Result<Vec<i32>, (usize, &'static str)> {
+        self.expect("(", "`(`")?;
+        let mut args = Vec::with_capacity(arity);
+        for idx in 0..arity {
+            if idx > 0 {
+                self.expect(",", "`,`")?;
+            }
+            args.push(self.number()?);
+        }
+        self.expect(")", "`)`")?;
+        Ok(args)
+    }
+
+    fn number(&mut self) -> Result<i32, (usize, &'static str)> {
+        let digits = self.input[self.pos..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        let number = self.input[self.pos..self.pos + digits]
+            .parse()
+            .map_err(|_| (self.pos, "a number"))?;
+        self.pos += digits;
+        Ok(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case("mul(1,2)", Token::new("mul", [1, 2]), 8)]
+    #[case("mul(1,2) ", Token::new("mul", [1, 2]), 8)]
+    #[case("do()", Token::new("do", []), 4)]
+    #[case("don't()", Token::new("don't", []), 7)]
+    fn test_instruction(#[case] input: &str, #[case] expected: Token, #[case] end: usize) {
+        let set = InstructionSet::standard();
+        assert_eq!(scan(input, &set).instruction(0), Ok((expected, end)));
+    }
+
+    #[rstest]
+    #[case(" mul(1,2) ")]
+    #[case("mul[1,2]")]
+    #[case("ul(1,2)")]
+    #[case("mul(1,-2)")]
+    fn test_instruction_fails(#[case] input: &str) {
+        let set = InstructionSet::standard();
+        assert!(scan(input, &set).instruction(0).is_err());
+    }
+
+    #[test]
+    fn test_scan_demo_input() {
+        let demo = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        let tokens: Vec<_> = scan(demo, &InstructionSet::standard()).tokens().collect();
+        assert_eq!(
+            tokens.iter().map(|t| t.token.clone()).collect::<Vec<_>>(),
+            [
+                Token::new("mul", [2, 4]),
+                Token::new("don't", []),
+                Token::new("mul", [5, 5]),
+                Token::new("mul", [11, 8]),
+                Token::new("do", []),
+                Token::new("mul", [8, 5]),
+            ]
+        );
+        assert_eq!(tokens[0].offset, 1);
+        assert_eq!(tokens[4].offset, 59);
+    }
+
+    #[test]
+    fn test_near_misses() {
+        let input = "mul(4*mul ( 2 , 4 )mul(32,64]do_mul(3,3)";
+        let set = InstructionSet::standard();
+        let near_misses: Vec<_> = scan(input, &set).near_misses().collect();
+        let found: Vec<_> = near_misses
+            .iter()
+            .map(|miss| (miss.offset, miss.text, miss.expected))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (0, "mul(4*", "`,`"),
+                (6, "mul ", "`(`"),
+                (19, "mul(32,64]", "`)`"),
+                (29, "do_", "`(`"),
+            ]
+        );
+        assert_eq!(near_misses[0].to_error(input).column, 6);
+        assert_eq!(scan(input, &set).tokens().count(), 1);
+    }
+}
@@ -0,0 +1,45 @@
+use aoc::{Answer, Direction8, Grid, LazyDetailed, ParseChars, ParseError, Solution};
+
+mod pattern;
+mod report;
+mod types;
+
+pub use pattern::*;
+pub use report::*;
+pub use types::*;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Answer {
+        let report = Report::new(
+            grid,
+            find_all(grid, &Pattern::word("XMAS", &Direction8::ALL)),
+        );
+        LazyDetailed {
+            answer: report.len(),
+            details: move || report.details(),
+        }
+    }
+
+    fn part2(grid: &Self::Input) -> impl Answer {
+        let x_mas = Pattern::stencil("M.S\n.A.\nM.S", '.', Symmetry::Rotations);
+        let report = Report::new(grid, find_all(grid, &x_mas));
+        LazyDetailed {
+            answer: report.len(),
+            details: move || report.details(),
+        }
+    }
+}
+
+aoc::examples! {
+    Day04 {
+        example: "example.txt", part1 = 18, part2 = 9;
+    }
+}
@@ -1,8 +0,0 @@
-mod part1;
-mod part2;
-mod types;
-
-fn main() {
-    part1::run();
-    part2::run();
-}
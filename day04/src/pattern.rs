@@ -0,0 +1,231 @@
+use crate::types::*;
+use aoc::{Direction8, Grid, Point};
+use std::{collections::HashSet, fmt::Display};
+
+/// how a pattern was turned before it matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Orientation {
+    /// a word read in this direction
+    Direction(Direction8),
+    /// a stencil mirrored left to right if `mirrored`, then turned clockwise
+    Turned { quarter_turns: u8, mirrored: bool },
+}
+
+impl Display for Orientation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Orientation::Direction(dir) => write!(f, "{dir:?}"),
+            Orientation::Turned {
+                quarter_turns,
+                mirrored: false,
+            } => write!(f, "turned {quarter_turns}x"),
+            Orientation::Turned {
+                quarter_turns,
+                mirrored: true,
+            } => write!(f, "mirrored, turned {quarter_turns}x"),
+        }
+    }
+}
+
+/// which orientations of a stencil are searched for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Symmetry {
+    /// only the stencil as given
+    None,
+    /// all four quarter turns
+    Rotations,
+    /// all quarter turns of the stencil and of its mirror image
+    RotationsAndReflections,
+}
+
+/// letters at fixed offsets from the start of the pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    letters: Vec<(Position, char)>,
+    orientation: Orientation,
+}
+
+impl Pattern {
+    /// `word` read in each of `directions`, starting at its first letter
+    pub fn word(word: &str, directions: &[Direction8]) -> Vec<Pattern> {
+        directions
+            .iter()
+            .map(|dir| Pattern {
+                letters: word
+                    .chars()
+                    .enumerate()
+                    .map(|(idx, c)| (dir.delta::<isize>() * idx as isize, c))
+                    .collect(),
+                orientation: Orientation::Direction(*dir),
+            })
+            .collect()
+    }
+
+    /// a block of letters where `wildcard` matches anything, starting at its top left
+    ///
+    /// Orientations which look the same as an earlier one are left out, so every
+    /// occurrence is only found once.
+    pub fn stencil(stencil: &str, wildcard: char, symmetry: Symmetry) -> Vec<Pattern> {
+        let cells: Vec<(Position, char)> = stencil
+            .lines()
+            .enumerate()
+            .flat_map(|(line, row)| {
+                row.chars()
+                    .enumerate()
+                    .map(move |(column, c)| (Point(line as isize, column as isize), c))
+            })
+            .collect();
+        let (quarter_turns, mirrored): (u8, &[bool]) = match symmetry {
+            Symmetry::None => (1, &[false]),
+            Symmetry::Rotations => (4, &[false]),
+            Symmetry::RotationsAndReflections => (4, &[false, true]),
+        };
+        let mut seen = HashSet::new();
+        let mut patterns = Vec::new();
+        for mirrored in mirrored.iter().copied() {
+            for quarter_turns in 0..quarter_turns {
+                let turned = turn(&cells, quarter_turns, mirrored);
+                let letters: Vec<_> = turned.into_iter().filter(|(_, c)| *c != wildcard).collect();
+                let mut key = letters.clone();
+                key.sort_by_key(|(p, _)| (p.0, p.1));
+                if seen.insert(key) {
+                    patterns.push(Pattern {
+                        letters,
+                        orientation: Orientation::Turned {
+                            quarter_turns,
+                            mirrored,
+                        },
+                    });
+                }
+            }
+        }
+        patterns
+    }
+
+    pub fn orientation(&self) -> Orientation {
+        self.orientation
+    }
+
+    /// the letters of the pattern if it matches at `start`
+    fn matches_at(&self, grid: &Grid<char>, start: Position) -> Option<Vec<Letter>> {
+        self.letters
+            .iter()
+            .map(|(offset, c)| {
+                let position = start + *offset;
+                (grid.get(position.0, position.1) == Some(c)).then_some(Letter {
+                    letter: *c,
+                    position,
+                })
+            })
+            .collect()
+    }
+}
+
+/// mirror and turn the cells of a stencil, then move them back to start at `(0, 0)`
+fn turn(cells: &[(Position, char)], quarter_turns: u8, mirrored: bool) -> Vec<(Position, char)> {
+    let turned: Vec<_> = cells
+        .iter()
+        .map(|(p, c)| {
+            let mut p = if mirrored { Point(p.0, -p.1) } else { *p };
+            for _ in 0..quarter_turns {
+                p = Point(p.1, -p.0);
+            }
+            (p, *c)
+        })
+        .collect();
+    let top = turned.iter().map(|(p, _)| p.0).min().unwrap_or(0);
+    let left = turned.iter().map(|(p, _)| p.1).min().unwrap_or(0);
+    turned
+        .into_iter()
+        .map(|(p, c)| (p - Point(top, left), c))
+        .collect()
+}
+
+/// an occurrence of a pattern in the grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    /// the first letter of a word or the top left corner of a stencil
+    pub start: Position,
+    pub orientation: Orientation,
+    /// the matched letters in pattern order, without wildcards
+    pub letters: Vec<Letter>,
+}
+
+/// every occurrence of every pattern, in reading order of their start
+///
+/// Each cell is compared with each pattern once, so this takes time linear in the grid
+/// size for a fixed set of patterns.
+pub fn find_all(grid: &Grid<char>, patterns: &[Pattern]) -> Vec<Match> {
+    let mut matches = Vec::new();
+    for ((line, column), _) in grid.indexed_iter() {
+        let start = Point(line as isize, column as isize);
+        for pattern in patterns {
+            if let Some(letters) = pattern.matches_at(grid, start) {
+                matches.push(Match {
+                    start,
+                    orientation: pattern.orientation,
+                    letters,
+                });
+            }
+        }
+    }
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::ParseChars;
+
+    fn grid(input: &str) -> Grid<char> {
+        Grid::parse_chars(input).unwrap()
+    }
+
+    #[test]
+    fn test_word() {
+        let grid = grid("XMAS\nMM..\nA.A.\nS..S\n");
+        let matches = find_all(&grid, &Pattern::word("XMAS", &Direction8::ALL));
+        let found: Vec<_> = matches.iter().map(|m| (m.start, m.orientation)).collect();
+        assert_eq!(
+            found,
+            [
+                (Point(0, 0), Orientation::Direction(Direction8::Right)),
+                (Point(0, 0), Orientation::Direction(Direction8::DownRight)),
+                (Point(0, 0), Orientation::Direction(Direction8::Down)),
+            ]
+        );
+        assert_eq!(matches[1].letters[3].position, Point(3, 3));
+    }
+
+    #[test]
+    fn test_stencil_orientations() {
+        let x_mas = "M.S\n.A.\nM.S";
+        assert_eq!(Pattern::stencil(x_mas, '.', Symmetry::None).len(), 1);
+        assert_eq!(Pattern::stencil(x_mas, '.', Symmetry::Rotations).len(), 4);
+        // mirror images of the X are just turned versions of it
+        assert_eq!(
+            Pattern::stencil(x_mas, '.', Symmetry::RotationsAndReflections).len(),
+            4
+        );
+        assert_eq!(
+            Pattern::stencil("AB\nC.", '.', Symmetry::RotationsAndReflections).len(),
+            8
+        );
+    }
+
+    #[test]
+    fn test_stencil_wildcards() {
+        let grid = grid("SXM\nXAX\nSXM\n");
+        let patterns = Pattern::stencil("M.S\n.A.\nM.S", '.', Symmetry::Rotations);
+        let matches = find_all(&grid, &patterns);
+        assert_eq!(matches.len(), 1);
+        assert_eq!(
+            matches[0].orientation,
+            Orientation::Turned {
+                quarter_turns: 2,
+                mirrored: false
+            }
+        );
+        assert_eq!(matches[0].letters.len(), 5);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::{types::*, Match, Orientation};
+use aoc::Grid;
+use serde_json::{json, Value};
+use std::{collections::BTreeMap, fmt::Display};
+
+/// overview of all matches of a word search
+///
+/// Its [`Display`] output lists every match, the counts and the grid with only the
+/// matched letters, like the illustrations in the puzzle.
+pub struct Report<'a> {
+    grid: &'a Grid<char>,
+    matches: Vec<Match>,
+}
+
+impl<'a> Report<'a> {
+    pub fn new(grid: &'a Grid<char>, matches: Vec<Match>) -> Self {
+        Self { grid, matches }
+    }
+
+    pub fn matches(&self) -> &[Match] {
+        &self.matches
+    }
+
+    /// number of matches
+    pub fn len(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// whether nothing was found
+    pub fn is_empty(&self) -> bool {
+        self.matches.is_empty()
+    }
+
+    /// number of matches for every orientation which was found
+    pub fn per_orientation(&self) -> BTreeMap<Orientation, usize> {
+        let mut counts = BTreeMap::new();
+        for m in self.matches.iter() {
+            *counts.entry(m.orientation).or_default() += 1;
+        }
+        counts
+    }
+
+    /// number of matches starting in each line
+    pub fn per_row(&self) -> Vec<usize> {
+        self.count_by(self.grid.rows(), |start| start.0)
+    }
+
+    /// number of matches starting in each column
+    pub fn per_column(&self) -> Vec<usize> {
+        self.count_by(self.grid.cols(), |start| start.1)
+    }
+
+    /// the grid with every letter which is not part of a match replaced by `.`
+    pub fn highlighted(&self) -> Grid<char> {
+        let mut highlighted = Grid::init(self.grid.rows(), self.grid.cols(), '.');
+        for Letter { letter, position } in self.matches.iter().flat_map(|m| m.letters.iter()) {
+            if let Some(cell) = highlighted.get_mut(position.0, position.1) {
+                *cell = *letter;
+            }
+        }
+        highlighted
+    }
+
+    /// the matches, counts and highlighted grid as JSON, see [`aoc::Answer::details`]
+    ///
+    /// Lines and columns of the matches start at 1 like in the [`Display`] output.
+    pub fn details(&self) -> Value {
+        let matches: Vec<Value> = self
+            .matches
+            .iter()
+            .map(|m| {
+                json!({
+                    "line": m.start.0 + 1,
+                    "column": m.start.1 + 1,
+                    "orientation": m.orientation.to_string(),
+                })
+            })
+            .collect();
+        let per_orientation: BTreeMap<String, usize> = self
+            .per_orientation()
+            .into_iter()
+            .map(|(orientation, count)| (orientation.to_string(), count))
+            .collect();
+        let highlighted = aoc::render(&self.highlighted()).to_string();
+        json!({
+            "matches": matches,
+            "per_orientation": per_orientation,
+            "per_row": self.per_row(),
+            "per_column": self.per_column(),
+            "highlighted": highlighted.lines().collect::<Vec<_>>(),
+        })
+    }
+
+    fn count_by(&self, len: usize, key: impl Fn(Position) -> isize) -> Vec<usize> {
+        let mut counts = vec![0; len];
+        for m in self.matches.iter() {
+            counts[key(m.start) as usize] += 1;
+        }
+        counts
+    }
+}
+
+impl Display for Report<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for m in self.matches.iter() {
+            writeln!(
+                f,
+                "line {}, column {}: {}",
+                m.start.0 + 1,
+                m.start.1 + 1,
+                m.orientation
+            )?;
+        }
+        writeln!(f, "{} matches", self.len())?;
+        for (orientation, count) in self.per_orientation() {
+            writeln!(f, "  {orientation}: {count}")?;
+        }
+        let join = |counts: Vec<usize>| {
+            counts
+                .iter()
+                .map(|count| count.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        writeln!(f, "per row: {}", join(self.per_row()))?;
+        writeln!(f, "per column: {}", join(self.per_column()))?;
+        write!(f, "{}", aoc::render(&self.highlighted()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{find_all, Pattern};
+    use aoc::{Direction8, ParseChars};
+
+    #[test]
+    fn test_report() {
+        let grid = Grid::parse_chars("XMASM\nMSXSA\nAMXAS\nSAMXM\n").unwrap();
+        let matches = find_all(&grid, &Pattern::word("XMAS", &Direction8::ALL));
+        let report = Report::new(&grid, matches);
+        assert_eq!(report.len(), 3);
+        assert_eq!(
+            report.per_orientation(),
+            BTreeMap::from([
+                (Orientation::Direction(Direction8::Right), 1),
+                (Orientation::Direction(Direction8::Down), 1),
+                (Orientation::Direction(Direction8::Left), 1),
+            ])
+        );
+        assert_eq!(report.per_row(), [2, 0, 0, 1]);
+        assert_eq!(report.per_column(), [2, 0, 0, 1, 0]);
+        assert_eq!(
+            aoc::render(&report.highlighted()).to_string(),
+            "XMAS.\nM....\nA....\nSAMX.\n"
+        );
+        assert_eq!(
+            report.details()["per_orientation"],
+            json!({ "Down": 1, "Left": 1, "Right": 1 })
+        );
+        assert_eq!(
+            report.details()["matches"][2],
+            json!({ "line": 4, "column": 4, "orientation": "Left" })
+        );
+        assert_eq!(
+            report.details()["highlighted"],
+            json!(["XMAS.", "M....", "A....", "SAMX."])
+        );
+    }
+}
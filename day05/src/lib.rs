@@ -0,0 +1,220 @@
+use aoc::{Answer, Detailed, ParseError, Solution};
+use serde_json::json;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    fmt::Display,
+};
+
+use nom::{bytes::complete::tag, character::complete::{self, line_ending}, multi::separated_list1, sequence::separated_pair, IResult};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (SpecialNumberOrdering, Vec<Vec<u64>>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::finish(input, parse_input(input))
+    }
+
+    fn part1((ord, updates): &Self::Input) -> impl Answer {
+        updates
+            .iter()
+            .filter(|update| ord.is_ordered(update))
+            .map(|update| update[update.len() / 2])
+            .sum::<u64>()
+    }
+
+    /// updates whose rules contradict each other can't be ordered, they are left out of
+    /// the answer and listed with one of their cycles in the details
+    fn part2((ord, updates): &Self::Input) -> impl Answer {
+        let mut sum = 0;
+        let mut cycles = Vec::new();
+        for update in updates.iter().filter(|update| !ord.is_ordered(update)) {
+            match ord.sort(update) {
+                Ok(sorted) => sum += sorted[sorted.len() / 2],
+                Err(Cycle(cycle)) => cycles.push(json!({ "update": update, "cycle": cycle })),
+            }
+        }
+        Detailed {
+            answer: sum,
+            details: json!({ "cycles": cycles }),
+        }
+    }
+}
+
+/// the page ordering rules, every page has to come before the pages it points to
+#[derive(Debug, Default)]
+pub struct SpecialNumberOrdering {
+    before: HashMap<u64, HashSet<u64>>,
+}
+
+/// pages which all have to come before the next one, and the last before the first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<u64>);
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for page in self.0.iter() {
+            write!(f, "{page} -> ")?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{first}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl SpecialNumberOrdering {
+    /// whether no rule puts a later page of `update` before an earlier one
+    pub fn is_ordered(&self, update: &[u64]) -> bool {
+        let positions = positions(update);
+        update.iter().enumerate().all(|(idx, page)| {
+            self.successors(*page)
+                .all(|next| positions.get(&next).is_none_or(|pos| *pos > idx))
+        })
+    }
+
+    /// the pages of `update` in an order which follows all rules between them
+    ///
+    /// Pages without a rule between them keep their relative order. Fails with one of
+    /// the cycles if the rules between the pages contradict each other.
+    pub fn sort(&self, update: &[u64]) -> Result<Vec<u64>, Cycle> {
+        let positions = positions(update);
+        // the precedence graph restricted to the update, by position
+        let mut successors = vec![Vec::new(); update.len()];
+        let mut predecessors = vec![Vec::new(); update.len()];
+        for (idx, page) in update.iter().enumerate() {
+            for next in self.successors(*page) {
+                if let Some(next_idx) = positions.get(&next) {
+                    successors[idx].push(*next_idx);
+                    predecessors[*next_idx].push(idx);
+                }
+            }
+        }
+
+        let mut in_degree: Vec<usize> = predecessors.iter().map(Vec::len).collect();
+        let mut ready: BinaryHeap<Reverse<usize>> = (0..update.len())
+            .filter(|idx| in_degree[*idx] == 0)
+            .map(Reverse)
+            .collect();
+        let mut sorted = Vec::with_capacity(update.len());
+        while let Some(Reverse(idx)) = ready.pop() {
+            sorted.push(update[idx]);
+            for next in successors[idx].iter() {
+                in_degree[*next] -= 1;
+                if in_degree[*next] == 0 {
+                    ready.push(Reverse(*next));
+                }
+            }
+        }
+        if sorted.len() == update.len() {
+            return Ok(sorted);
+        }
+
+        // every page left over still has a predecessor which is left over, so walking
+        // backwards has to run into a cycle
+        let mut current = (0..update.len())
+            .find(|idx| in_degree[*idx] > 0)
+            .expect("some page is left over");
+        let mut walked = Vec::new();
+        let mut seen_at: HashMap<usize, usize> = HashMap::new();
+        while !seen_at.contains_key(&current) {
+            seen_at.insert(current, walked.len());
+            walked.push(current);
+            current = *predecessors[current]
+                .iter()
+                .find(|prev| in_degree[**prev] > 0)
+                .expect("left over pages have a left over predecessor");
+        }
+        let cycle = walked[seen_at[&current]..]
+            .iter()
+            .rev()
+            .map(|idx| update[*idx])
+            .collect();
+        Err(Cycle(cycle))
+    }
+
+    /// the pages `page` has to come before
+    fn successors(&self, page: u64) -> impl Iterator<Item = u64> + '_ {
+        self.before.get(&page).into_iter().flatten().copied()
+    }
+}
+
+/// the index of every page in `update`
+fn positions(update: &[u64]) -> HashMap<u64, usize> {
+    update
+        .iter()
+        .enumerate()
+        .map(|(idx, page)| (*page, idx))
+        .collect()
+}
+
+impl From<Vec<(u64, u64)>> for SpecialNumberOrdering {
+    fn from(value: Vec<(u64, u64)>) -> Self {
+        let mut ord = SpecialNumberOrdering::default();
+        for (first, second) in value {
+            ord.before.entry(first).or_default().insert(second);
+        }
+        ord
+    }
+}
+
+fn parse_ordering(input: &str) -> IResult<&str, (u64, u64)> {
+    separated_pair(complete::u64, tag("|"), complete::u64)(input)
+}
+
+fn parse_input(input: &str) -> IResult<&str, (SpecialNumberOrdering, Vec<Vec<u64>>)> {
+    let (input, pairs) = separated_list1(line_ending, parse_ordering)(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, orders) =
+        separated_list1(line_ending, separated_list1(tag(","), complete::u64))(input)?;
+    let special_ordering = SpecialNumberOrdering::from(pairs);
+    Ok((input, (special_ordering, orders)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sort() {
+        let ord = SpecialNumberOrdering::from(vec![(1, 2), (2, 3), (4, 3)]);
+        assert!(ord.is_ordered(&[1, 2, 4, 3]));
+        assert!(!ord.is_ordered(&[3, 1, 2]));
+        assert_eq!(ord.sort(&[3, 2, 1]), Ok(vec![1, 2, 3]));
+        // unrelated pages keep their order
+        assert_eq!(ord.sort(&[3, 4, 5, 1]), Ok(vec![4, 3, 5, 1]));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let ord = SpecialNumberOrdering::from(vec![(1, 2), (2, 3), (3, 1), (4, 1)]);
+        assert_eq!(ord.sort(&[4, 2, 3]), Ok(vec![4, 2, 3]));
+        let Err(cycle) = ord.sort(&[4, 1, 2, 3]) else {
+            panic!("the rules contain a cycle");
+        };
+        assert_eq!(cycle, Cycle(vec![2, 3, 1]));
+        assert_eq!(cycle.to_string(), "2 -> 3 -> 1 -> 2");
+    }
+
+    #[test]
+    fn test_contradicting_rules() {
+        let input = Day05::parse("1|2\n2|1\n4|3\n\n1,3,5\n3,2,1\n3,4,5\n").unwrap();
+        // ordered updates don't need to be sorted
+        assert_eq!(Day05::part1(&input).to_string(), "3");
+        let part2 = Day05::part2(&input);
+        assert_eq!(part2.to_string(), "3");
+        assert_eq!(
+            part2.details(),
+            Some(json!({ "cycles": [{ "update": [3, 2, 1], "cycle": [1, 2] }] }))
+        );
+    }
+}
+
+aoc::examples! {
+    Day05 {
+        example: "example.txt", part1 = 143, part2 = 123;
+    }
+}
@@ -0,0 +1,155 @@
+use aoc::{
+    Answer, BitGrid, Direction, Frames, Grid, GridNeighbours, ParseChars, ParseError, Point, Rgb,
+    Solution,
+};
+use rayon::prelude::*;
+use std::fmt::{Debug, Display};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Point, Grid<Place>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1((start, g): &Self::Input) -> impl Answer {
+        let mut frames = Frames::from_env("day06");
+        walk_guard(*start, &obstacles(g), &mut frames).data.len()
+    }
+
+    fn part2((start, g): &Self::Input) -> impl Answer {
+        let obstacles = obstacles(g);
+        let path = walk_guard(*start, &obstacles, &mut None);
+        // the start is part of the path, but can't get an obstacle
+        path.data
+            .par_iter()
+            .filter(|point| *point != start)
+            .flat_map(|point| {
+                let new_obstacles = place_obstacle(&obstacles, *point);
+                let path = walk_guard(*start, &new_obstacles, &mut None);
+                if path.found_loop {
+                    Some(())
+                } else {
+                    None
+                }
+            })
+            .count()
+    }
+}
+
+struct Path {
+    data: Vec<Point>,
+    found_loop: bool,
+}
+
+/// follow the guard until it leaves the map or walks in a loop
+///
+/// Every step is written to `frames` if given.
+fn walk_guard(start: Point, obstacles: &BitGrid, frames: &mut Option<Frames>) -> Path {
+    let mut direction = Direction::Up;
+    let mut current_position = start;
+    // the visited cells for every direction the guard was facing there
+    let mut visited = [(); 4].map(|_| BitGrid::new(obstacles.rows(), obstacles.cols()));
+    let mut found_loop = false;
+    'outer: loop {
+        if !visited[direction as usize].insert(current_position) {
+            found_loop = true;
+            break;
+        }
+        save_frame(frames, obstacles, &visited, current_position);
+        'inner: loop {
+            let Some(next) = obstacles.step(current_position, direction) else {
+                break 'outer;
+            };
+            if obstacles[next] {
+                direction = direction.turn_right();
+            } else {
+                current_position = next;
+                break 'inner;
+            }
+        }
+    }
+    let mut all_visited = visited[0].clone();
+    for v in &visited[1..] {
+        all_visited |= v;
+    }
+    Path {
+        data: all_visited.iter().collect(),
+        found_loop,
+    }
+}
+
+fn place_obstacle(obstacles: &BitGrid, pos: Point) -> BitGrid {
+    let mut out = obstacles.clone();
+    out.insert(pos);
+    out
+}
+
+fn obstacles(grid: &Grid<Place>) -> BitGrid {
+    BitGrid::from_grid(grid, |place| *place == Place::Obstacle)
+}
+
+#[allow(unused)]
+fn print_grid(grid: &Grid<Place>, path: &[Point]) {
+    print!("{}", aoc::render(grid).overlay(path.iter().copied(), 'X'));
+}
+
+/// write the map with the guard and the cells it visited so far as next frame
+fn save_frame(frames: &mut Option<Frames>, obstacles: &BitGrid, visited: &[BitGrid], guard: Point) {
+    if frames.is_none() {
+        return;
+    }
+    let mut image: Grid<Rgb> = Grid::new(obstacles.rows(), obstacles.cols());
+    for p in obstacles.iter() {
+        image[p] = [255, 255, 255];
+    }
+    for p in visited.iter().flat_map(|v| v.iter()) {
+        image[p] = [255, 0, 0];
+    }
+    image[guard] = [0, 255, 0];
+    Frames::record_ppm(frames, &image, |color| *color);
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Place {
+    #[default]
+    Empty,
+    Obstacle,
+    Start,
+}
+
+impl Display for Place {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Place::Empty => write!(f, "."),
+            Place::Obstacle => write!(f, "#"),
+            Place::Start => write!(f, "^"),
+        }
+    }
+}
+
+impl TryFrom<char> for Place {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Obstacle),
+            '^' => Ok(Self::Start),
+            c => Err(format!("unknown place {c}")),
+        }
+    }
+}
+
+fn parse_map(input: &str) -> Result<(Point, Grid<Place>), ParseError> {
+    let (grid, markers) = Grid::parse_chars_with_markers(input, &['^'])?;
+    Ok((markers[&'^'], grid))
+}
+
+aoc::examples! {
+    Day06 {
+        example: "example.txt", part1 = 41, part2 = 6;
+    }
+}
@@ -1,3 +1,4 @@
+use aoc::{Answer, ParseError, Solution};
 use nom::{
     bytes::complete::tag,
     character::complete::{self, line_ending, space1},
@@ -6,20 +7,28 @@ use nom::{
     IResult,
 };
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, test) = parse_input(input).unwrap();
-    let part1: u64 = test
-        .iter()
-        .filter_map(|(result, nums)| part1(*result, &nums[1..nums.len()], nums[0]))
-        .sum();
-    println!("part1 {part1}");
+pub struct Day07;
 
-    let part2: u64 = test
-        .iter()
-        .filter_map(|(result, nums)| part2(*result, &nums[1..nums.len()], nums[0]))
-        .sum();
-    println!("part2 {part2}")
+impl Solution for Day07 {
+    type Input = Vec<(u64, Vec<u64>)>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::finish(input, parse_input(input))
+    }
+
+    fn part1(input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .filter_map(|(result, nums)| part1(*result, &nums[1..nums.len()], nums[0]))
+            .sum::<u64>()
+    }
+
+    fn part2(input: &Self::Input) -> impl Answer {
+        input
+            .iter()
+            .filter_map(|(result, nums)| part2(*result, &nums[1..nums.len()], nums[0]))
+            .sum::<u64>()
+    }
 }
 
 fn parse_input(input: &str) -> IResult<&str, Vec<(u64, Vec<u64>)>> {
@@ -78,3 +87,9 @@ fn part2(result: u64, nums: &[u64], acc: u64) -> Option<u64> {
     .filter_map(|x| *x)
     .next()
 }
+
+aoc::examples! {
+    Day07 {
+        example: "example.txt", part1 = 3749, part2 = 11387;
+    }
+}
@@ -0,0 +1,189 @@
+use aoc::{Answer, ParseChars, ParseError, Point, Solution};
+use itertools::Itertools;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    str::FromStr,
+};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Grid;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::from_str(input)
+    }
+
+    fn part1(grid: &Self::Input) -> impl Answer {
+        let mut part1 = grid.clone();
+        part1.find_antinodes_part1();
+        part1.count()
+    }
+
+    fn part2(grid: &Self::Input) -> impl Answer {
+        let mut part2 = grid.clone();
+        part2.find_antinodes_part2();
+        part2.count()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Grid {
+    data: HashMap<Point<isize>, char>,
+    antinodes: HashSet<Point<isize>>,
+    found_towers: HashSet<char>,
+    line_max: isize,
+    char_max: isize,
+}
+
+impl Grid {
+    fn add_antinode(&mut self, p: Point<isize>) {
+        if self.is_in_bounds(p) {
+            self.antinodes.insert(p);
+        }
+    }
+
+    fn find_antinodes_part1(&mut self) {
+        let mut to_add = Vec::default();
+        for tower_type in self.found_towers.iter() {
+            for tuple in self
+                .data
+                .iter()
+                .filter(|(_coord, c)| *c == tower_type)
+                .tuple_combinations::<((&Point<isize>, &char), (&Point<isize>, &char))>()
+            {
+                let ((a, _), (b, _)) = tuple;
+                let vector = a - b;
+                to_add.push(a + vector);
+                to_add.push(b - vector);
+            }
+        }
+        to_add.into_iter().for_each(|p| {
+            self.add_antinode(p);
+        });
+    }
+
+    fn is_in_bounds(&self, p: Point<isize>) -> bool {
+        p.0 <= self.line_max && p.1 <= self.char_max && p.0 >= 0 && p.1 >= 0
+    }
+
+    fn find_antinodes_part2(&mut self) {
+        let mut to_add = Vec::default();
+        for tower_type in self.found_towers.iter() {
+            for tuple in self
+                .data
+                .iter()
+                .filter(|(_coord, c)| *c == tower_type)
+                .tuple_combinations::<((&Point<isize>, &char), (&Point<isize>, &char))>()
+            {
+                let ((a, _), (b, _)) = tuple;
+
+                // first add both towers since they are themselve in line
+                to_add.push(*a);
+                to_add.push(*b);
+
+                // then add the difference up and add the antinode as long as the new antinode is still inside the bounds
+                let initial_vector = a - b;
+                let mut vector = initial_vector;
+                while self.is_in_bounds(a + vector) {
+                    to_add.push(a + vector);
+                    vector += initial_vector;
+                }
+
+                let mut vector = initial_vector;
+                while self.is_in_bounds(b - vector) {
+                    to_add.push(b - vector);
+                    vector += initial_vector;
+                }
+            }
+        }
+        to_add.into_iter().for_each(|p| {
+            self.add_antinode(p);
+        });
+    }
+
+    fn count(&self) -> usize {
+        self.antinodes.len()
+    }
+}
+
+/// a single place of the map
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Cell {
+    #[default]
+    Empty,
+    /// antenna with its frequency
+    Antenna(char),
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            c if c.is_ascii_alphanumeric() => Ok(Self::Antenna(c)),
+            c => Err(format!("unknown cell {c}")),
+        }
+    }
+}
+
+impl FromStr for Grid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let map = aoc::Grid::<Cell>::parse_chars(s)?;
+        let mut g = Grid {
+            data: HashMap::default(),
+            antinodes: HashSet::default(),
+            found_towers: HashSet::default(),
+            line_max: map.rows() as isize - 1,
+            char_max: map.cols() as isize - 1,
+        };
+        for ((line_idx, char_idx), cell) in map.indexed_iter() {
+            if let Cell::Antenna(c) = cell {
+                g.data
+                    .insert(Point(line_idx as isize, char_idx as isize), *c);
+                g.found_towers.insert(*c);
+            }
+        }
+        Ok(g)
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (rows, cols) = (self.line_max as usize + 1, self.char_max as usize + 1);
+        let mut map = aoc::Grid::init(rows, cols, '.');
+        for (p, c) in self.data.iter() {
+            if let Some(p) = p.grid_index(&map) {
+                map[p] = *c;
+            }
+        }
+        let antinodes = self
+            .antinodes
+            .iter()
+            .filter(|p| !self.data.contains_key(p))
+            .flat_map(|p| p.grid_index(&map));
+        write!(f, "{}", aoc::render(&map).overlay(antinodes, '#'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_unknown_cell() {
+        let err = Day08::parse("..a\n.#.\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.expected, "a grid cell (unknown cell #)");
+    }
+}
+
+aoc::examples! {
+    Day08 {
+        example: "example.txt", part1 = 14, part2 = 34;
+    }
+}
@@ -1,23 +1,34 @@
+use aoc::{Answer, ParseError, Solution};
 use std::{
     fmt::{Debug, Display},
     ops::AddAssign,
     str::FromStr,
 };
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let layout = DiskLayout::from_str(input).expect("layout should be valid");
-    let mut part1 = layout.clone();
-    part1.compact_part1();
-    println!("part1 checksum {}", part1.checksum());
+pub struct Day09;
 
-    let mut part2 = layout.clone();
-    part2.compact_part2();
-    println!("part2 checksum {}", part2.checksum());
+impl Solution for Day09 {
+    type Input = DiskLayout;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        DiskLayout::from_str(input)
+    }
+
+    fn part1(layout: &Self::Input) -> impl Answer {
+        let mut part1 = layout.clone();
+        part1.compact_part1();
+        part1.checksum()
+    }
+
+    fn part2(layout: &Self::Input) -> impl Answer {
+        let mut part2 = layout.clone();
+        part2.compact_part2();
+        part2.checksum()
+    }
 }
 
 #[derive(Debug, Clone)]
-struct DiskLayout {
+pub struct DiskLayout {
     layout: Vec<BlockType>,
 }
 
@@ -115,14 +126,27 @@ impl Display for DiskLayout {
 }
 
 impl FromStr for DiskLayout {
-    type Err = ();
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let v: Vec<usize> = s
-            .chars()
-            .filter_map(|c| c.to_digit(10))
-            .map(|x| x as usize)
-            .collect();
+            .trim_end()
+            .char_indices()
+            .enumerate()
+            .map(|(n, (idx, c))| {
+                // files take at least one block, free space may be empty
+                let min = if n % 2 == 0 { 1 } else { 0 };
+                c.to_digit(10)
+                    .map(|x| x as usize)
+                    .filter(|x| *x >= min)
+                    .ok_or_else(|| {
+                        ParseError::new(s, &s[idx..], format!("a digit from {min} to 9"))
+                    })
+            })
+            .collect::<Result<_, _>>()?;
+        if v.is_empty() {
+            return Err(ParseError::new(s, s, "at least one file"));
+        }
         let mut layout: Vec<BlockType> = Vec::default();
         let mut found_files = 0_usize;
         for (idx, block_size) in v.iter().enumerate() {
@@ -174,3 +198,25 @@ impl Display for BlockType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_errors() {
+        let empty = Day09::parse("\n").unwrap_err();
+        assert_eq!(empty.expected, "at least one file");
+        let letter = Day09::parse("12x4").unwrap_err();
+        assert_eq!(letter.column, 3);
+        assert_eq!(letter.expected, "a digit from 1 to 9");
+        // a file without blocks
+        assert!(Day09::parse("0512").is_err());
+    }
+}
+
+aoc::examples! {
+    Day09 {
+        example: "example.txt", part1 = 1928, part2 = 2858;
+    }
+}
@@ -0,0 +1,94 @@
+use aoc::{Adjacency, Answer, Grid, GridNeighbours, ParseError, Point, Solution};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let grid: Vec<Vec<_>> = input
+            .lines()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(idx, c)| {
+                        c.to_digit(10)
+                            .map(|d| d as usize)
+                            .ok_or_else(|| ParseError::new(input, &line[idx..], "a digit"))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+        let width = grid.first().map_or(0, |row| row.len());
+        if let Some((line, _)) = input
+            .lines()
+            .zip(grid.iter())
+            .find(|(_, row)| row.len() != width)
+        {
+            return Err(ParseError::new(input, line, format!("{width} digits")));
+        }
+        Ok(grid.into())
+    }
+
+    fn part1(grid: &Self::Input) -> impl Answer {
+        part1(grid)
+    }
+
+    fn part2(grid: &Self::Input) -> impl Answer {
+        part2(grid)
+    }
+}
+
+fn part1(grid: &Grid<usize>) -> usize {
+    trailheads(grid)
+        .map(|start| {
+            let reachable = aoc::bfs(grid, start, |from, to| grid[to] == grid[from] + 1);
+            grid.indexed_iter()
+                .filter(|(p, height)| **height == 9 && reachable[*p].is_some())
+                .count()
+        })
+        .sum()
+}
+
+fn part2(grid: &Grid<usize>) -> usize {
+    // number of trails from every cell up to a summit, counted from the summits downwards
+    let mut trails: Grid<usize> = Grid::new(grid.rows(), grid.cols());
+    for height in (0..=9).rev() {
+        for ((line, col), _) in grid.indexed_iter().filter(|(_, h)| **h == height) {
+            let p = Point(line, col);
+            trails[p] = if height == 9 {
+                1
+            } else {
+                grid.neighbours(p, Adjacency::Cardinal)
+                    .filter(|next| grid[next] == height + 1)
+                    .map(|next| trails[next])
+                    .sum()
+            };
+        }
+    }
+    trailheads(grid).map(|start| trails[start]).sum()
+}
+
+fn trailheads(grid: &Grid<usize>) -> impl Iterator<Item = Point> + '_ {
+    grid.indexed_iter()
+        .filter(|(_, height)| **height == 0)
+        .map(|((line_idx, col_idx), _)| Point(line_idx, col_idx))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        // 12 cells would fit into a 3 by 4 grid as well
+        let err = Day10::parse("0123\n12\n345678\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "4 digits");
+    }
+}
+
+aoc::examples! {
+    Day10 {
+        example: "example.txt", part1 = 36, part2 = 81;
+    }
+}
@@ -1,31 +1,37 @@
-use cached::proc_macro::cached;
+use aoc::{Answer, ParseError, Solution};
+use cached::{proc_macro::cached, Cached};
 use num_traits::Euclid;
 use std::collections::HashMap;
-use std::time::Instant;
 
-fn main() {
-    let numbers = include_str!("../input.txt")
-        .split_ascii_whitespace()
-        .map(|x| x.parse::<usize>().expect("input should be valid"))
-        .collect::<Vec<_>>();
-    let initial_stone_counts: HashMap<usize, usize> =
-        numbers.iter().map(|x| (*x, 1_usize)).collect();
-    let part1 = blink(25, initial_stone_counts.clone());
-    println!("part1 - {part1}");
+pub struct Day11;
 
-    const BLINK_COUNT: usize = 75;
-    let now = Instant::now();
-    let part2 = blink(BLINK_COUNT, initial_stone_counts.clone());
-    let elapsed = now.elapsed();
-    println!("part2 - {part2} - time: {:?}", elapsed);
+impl Solution for Day11 {
+    type Input = HashMap<usize, usize>;
 
-    // test recurse
-    let now = Instant::now();
-    let recurse = numbers
-        .iter()
-        .fold(0, |acc, stone| acc + recurse_stone(*stone, BLINK_COUNT));
-    let elapsed = now.elapsed();
-    println!("recurse - {recurse} - time: {:?}", elapsed);
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .split_ascii_whitespace()
+            .map(|x| {
+                x.parse::<usize>()
+                    .map_err(|_| ParseError::new(input, x, "a number"))
+            })
+            .map(|x| x.map(|x| (x, 1_usize)))
+            .collect()
+    }
+
+    fn part1(initial_stone_counts: &Self::Input) -> impl Answer {
+        blink(25, initial_stone_counts.clone())
+    }
+
+    fn part2(initial_stone_counts: &Self::Input) -> impl Answer {
+        blink(75, initial_stone_counts.clone())
+    }
+
+    fn reset() {
+        LEN.lock().unwrap().cache_clear();
+        STEP.lock().unwrap().cache_clear();
+        RECURSE_STONE.lock().unwrap().cache_clear();
+    }
 }
 
 fn blink(count: usize, mut stone_counts: HashMap<usize, usize>) -> usize {
@@ -55,7 +61,7 @@ fn step(input: usize) -> Vec<usize> {
     let num_digits = len(input);
     match input {
         0 => result.push(1),
-        x if num_digits % 2 == 0 => {
+        x if num_digits.is_multiple_of(2) => {
             let (left, right) = x.div_rem_euclid(&10usize.pow(num_digits / 2));
             result.push(left);
             result.push(right);
@@ -65,6 +71,8 @@ fn step(input: usize) -> Vec<usize> {
     result
 }
 
+/// alternative to [`blink`] which recursively follows every single stone
+#[allow(dead_code)]
 #[cached]
 fn recurse_stone(stone: usize, step_count: usize) -> usize {
     if step_count == 0 {
@@ -73,10 +81,16 @@ fn recurse_stone(stone: usize, step_count: usize) -> usize {
     let num_digits = len(stone);
     match stone {
         0 => recurse_stone(1, step_count - 1),
-        x if num_digits % 2 == 0 => {
+        x if num_digits.is_multiple_of(2) => {
             let (left, right) = x.div_rem_euclid(&10usize.pow(num_digits / 2));
             recurse_stone(left, step_count - 1) + recurse_stone(right, step_count - 1)
         }
         x => recurse_stone(x * 2024, step_count - 1),
     }
 }
+
+aoc::examples! {
+    Day11 {
+        example: "example.txt", part1 = 55312, part2 = 65601038650482_u64;
+    }
+}
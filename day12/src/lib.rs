@@ -0,0 +1,35 @@
+use aoc::{Answer, Grid, ParseChars, ParseError, Region, Solution};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Region>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let g: Grid<char> = Grid::parse_chars(input)?;
+
+        // every plot of plants of the same type is a region
+        Ok(aoc::components(&g, |a, b| a == b)
+            .regions()
+            .into_iter()
+            .map(Region::from_iter)
+            .collect())
+    }
+
+    fn part1(regions: &Self::Input) -> impl Answer {
+        regions
+            .iter()
+            .map(|r| r.area() * r.perimeter())
+            .sum::<usize>()
+    }
+
+    fn part2(regions: &Self::Input) -> impl Answer {
+        regions.iter().map(|r| r.area() * r.sides()).sum::<usize>()
+    }
+}
+
+aoc::examples! {
+    Day12 {
+        example: "example.txt", part1 = 1930, part2 = 1206;
+    }
+}
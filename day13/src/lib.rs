@@ -1,5 +1,6 @@
 use std::ops::Sub;
 
+use aoc::{Answer, ParseError, Solution};
 use glam::DVec2;
 use nom::{
     bytes::complete::{tag, take, take_until1},
@@ -10,19 +11,26 @@ use nom::{
     IResult, Parser,
 };
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_input, machines) = parse(input).unwrap();
+pub struct Day13;
 
-    let part1: usize = machines.iter().map(|m| m.part1()).sum();
-    println!("part1 {part1}");
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
 
-    let part2: usize = machines.iter().map(|m| m.part2()).sum();
-    println!("part1 {part2}");
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::finish(input, parse(input))
+    }
+
+    fn part1(machines: &Self::Input) -> impl Answer {
+        machines.iter().map(|m| m.part1()).sum::<usize>()
+    }
+
+    fn part2(machines: &Self::Input) -> impl Answer {
+        machines.iter().map(|m| m.part2()).sum::<usize>()
+    }
 }
 
 #[derive(Debug)]
-struct Machine {
+pub struct Machine {
     a: DVec2,
     b: DVec2,
     prize: DVec2,
@@ -129,3 +137,9 @@ mod test {
         assert_eq!(m.part1(), r)
     }
 }
+
+aoc::examples! {
+    Day13 {
+        example: "example.txt", part1 = 480, part2 = 875318608908_u64;
+    }
+}
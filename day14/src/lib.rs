@@ -0,0 +1,216 @@
+use aoc::{Answer, Frames, Grid, GridNeighbours, ParseError, Point, Solution, SparseGrid};
+use cached::{proc_macro::cached, Cached};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    multi::separated_list0,
+    sequence::{preceded, separated_pair},
+    IResult, Parser,
+};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+const FIELD_HIGHT: usize = 103;
+const FIELD_WIDTH: usize = 101;
+const SECONDS: usize = 100;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Lobby;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Lobby {
+            robots: aoc::finish(input, parse(input))?,
+            size: (FIELD_HIGHT, FIELD_WIDTH),
+        })
+    }
+
+    fn part1(lobby: &Self::Input) -> impl Answer {
+        part1(&lobby.robots, lobby.size)
+    }
+
+    fn part2(lobby: &Self::Input) -> impl Answer {
+        part2(&lobby.robots, lobby.size)
+    }
+
+    fn reset() {
+        MOVE_ROBOT.lock().unwrap().cache_clear();
+    }
+}
+
+/// the robots and the room they move in
+pub struct Lobby {
+    robots: Vec<Robot>,
+    /// `(height, width)` like the positions
+    size: (usize, usize),
+}
+
+impl Lobby {
+    /// a room of `width` by `height` tiles instead of the real one, e.g. 11 by 7 for the example
+    pub fn with_size(self, width: usize, height: usize) -> Self {
+        Self {
+            size: (height, width),
+            ..self
+        }
+    }
+}
+
+fn part1(robots: &[Robot], size: (usize, usize)) -> usize {
+    let robots = robots
+        .into_par_iter()
+        .map(|r| {
+            let new_r = move_robot(r.position, r.velocity, SECONDS, size);
+            Robot {
+                position: new_r,
+                velocity: r.velocity,
+            }
+        })
+        .collect::<Vec<_>>();
+    get_safety_factor(&robots, size)
+}
+
+fn part2(robots: &[Robot], size: (usize, usize)) -> usize {
+    let mut frames = Frames::from_env("day14");
+    (0..usize::MAX)
+        .find(|step_count| {
+            let robots = robots
+                .into_par_iter()
+                .map(|r| {
+                    let new_r = move_robot(r.position, r.velocity, *step_count, size);
+                    Robot {
+                        position: new_r,
+                        velocity: r.velocity,
+                    }
+                })
+                .collect::<Vec<_>>();
+            save_frame(&mut frames, &robots, size);
+
+            // this seems to work?
+            // not sure if on every input...
+            let c = counter(&robots);
+            robots.len() == c.len()
+        })
+        .expect("the robots should form a picture at some point")
+}
+
+fn get_safety_factor(robots: &[Robot], size: (usize, usize)) -> usize {
+    let q = robots.iter().fold((0, 0, 0, 0), |mut acc, r| {
+        if let Some(q) = r.get_quadrant(size) {
+            match q {
+                Quadrant::UpperLeft => acc.0 += 1,
+                Quadrant::UpperRight => acc.1 += 1,
+                Quadrant::LowerLeft => acc.2 += 1,
+                Quadrant::LowerRight => acc.3 += 1,
+            }
+        };
+        acc
+    });
+    q.0 * q.1 * q.2 * q.3
+}
+
+/// positions and velocities are `Point(y, x)`
+#[derive(Debug)]
+pub struct Robot {
+    position: Point,
+    velocity: Point<isize>,
+}
+
+enum Quadrant {
+    UpperLeft,
+    UpperRight,
+    LowerLeft,
+    LowerRight,
+}
+
+impl Robot {
+    fn get_quadrant(&self, size: (usize, usize)) -> Option<Quadrant> {
+        let mid_x = size.1 / 2;
+        let mid_y = size.0 / 2;
+        let Point(y, x) = self.position;
+
+        // upper left
+        if x < mid_x && y < mid_y {
+            return Some(Quadrant::UpperLeft);
+        };
+
+        // upper right
+        if x > mid_x && y < mid_y {
+            return Some(Quadrant::UpperRight);
+        };
+
+        // lower left
+        if x < mid_x && y > mid_y {
+            return Some(Quadrant::LowerLeft);
+        };
+
+        // lower right
+        if x > mid_x && y > mid_y {
+            return Some(Quadrant::LowerRight);
+        };
+        None
+    }
+}
+
+fn parse_vec(input: &str) -> IResult<&str, Point<isize>> {
+    separated_pair(complete::i64, tag(","), complete::i64)
+        .map(|(x, y)| Point(y as isize, x as isize))
+        .parse(input)
+}
+
+fn parse_position(input: &str) -> IResult<&str, Point> {
+    separated_pair(complete::u64, tag(","), complete::u64)
+        .map(|(x, y)| Point(y as usize, x as usize))
+        .parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Robot> {
+    let (input, position) = preceded(tag("p="), parse_position)(input)?;
+    let (input, velocity) = preceded(tag(" v="), parse_vec)(input)?;
+    Ok((input, Robot { position, velocity }))
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Robot>> {
+    separated_list0(line_ending, parse_line)(input)
+}
+
+#[cached]
+fn move_robot(
+    position: Point,
+    velocity: Point<isize>,
+    step_count: usize,
+    size: (usize, usize),
+) -> Point {
+    size.wrapping_offset(position, velocity * step_count as isize)
+}
+
+#[allow(dead_code)]
+fn print_field(robots: &[Robot]) {
+    print!("{}", counter(robots));
+}
+
+/// write the robots as next PGM frame, so the tree can be spotted in an image viewer
+fn save_frame(frames: &mut Option<Frames>, robots: &[Robot], size: (usize, usize)) {
+    if frames.is_none() {
+        return;
+    }
+    let mut field: Grid<bool> = Grid::new(size.0, size.1);
+    for robot in robots {
+        field[robot.position] = true;
+    }
+    Frames::record_pgm(frames, &field, |robot| if *robot { 255 } else { 0 });
+}
+
+fn counter(input: &[Robot]) -> SparseGrid<usize> {
+    let mut h = SparseGrid::new();
+    for robot in input {
+        let Point(y, x) = robot.position;
+        *h.entry(Point(y as isize, x as isize)).or_insert(0) += 1;
+    }
+    h
+}
+
+aoc::examples! {
+    Day14 {
+        example: "example.txt", with = |lobby| lobby.with_size(11, 7), part1 = 12;
+    }
+}
@@ -3,35 +3,54 @@ use std::{
     fmt::Debug,
 };
 
-use aoc::{Grid, Point};
+use aoc::{Answer, Direction, Frames, Grid, ParseError, Point, Rgb, Solution};
 mod parse;
 mod types;
 use parse::*;
 use types::*;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_input, (mut grid, path)) = parse(input).unwrap();
-    // dbg!(&input, &grid, &path);
-    let mut bigger_grid = blow_up_grid(&grid);
-    move_robot(&mut grid, &path);
-    print_grid(&grid);
-    let part1 = part1(&grid);
-    println!("part1: {part1}");
-
-    move_robot_p2(&mut bigger_grid, &path);
-    print_grid(&bigger_grid);
-    let part2 = part2(&bigger_grid);
-    println!("part1: {part2}");
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = (Grid<Cell>, Point, Vec<Direction>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((grid, robot, path): &Self::Input) -> impl Answer {
+        let mut grid = grid.clone();
+        move_robot(
+            &mut grid,
+            *robot,
+            path,
+            &mut Frames::from_env("day15/part1"),
+        );
+        part1(&grid)
+    }
+
+    fn part2((grid, robot, path): &Self::Input) -> impl Answer {
+        let mut bigger_grid = blow_up_grid(grid);
+        move_robot_p2(
+            &mut bigger_grid,
+            Point(robot.0, robot.1 * 2),
+            path,
+            &mut Frames::from_env("day15/part2"),
+        );
+        part2(&bigger_grid)
+    }
 }
 
-fn move_robot(grid: &mut Grid<Cell>, path: &[Direction]) {
-    let mut curr_pos: Point = grid
-        .indexed_iter()
-        .find(|(_, c)| **c == Cell::Robot)
-        .map(|(pos, _)| pos.into())
-        .expect("Robot exists");
+/// every step is written to `frames` if given
+fn move_robot(
+    grid: &mut Grid<Cell>,
+    robot: Point,
+    path: &[Direction],
+    frames: &mut Option<Frames>,
+) {
+    let mut curr_pos = robot;
     // print_grid(&grid);
+    Frames::record_ppm(frames, grid, cell_color);
     for dir in path {
         // println!("Move {:?}:", dir);
         let dir_vec: (i32, i32) = (*dir).into();
@@ -50,6 +69,7 @@ fn move_robot(grid: &mut Grid<Cell>, path: &[Direction]) {
             }
         }
         // print_grid(&grid);
+        Frames::record_ppm(frames, grid, cell_color);
     }
 }
 
@@ -170,16 +190,20 @@ fn move_crates_p2(grid: &mut Grid<BiggerCell>, pos: Point, dir: (i32, i32)) -> O
     new_robot_position
 }
 
-fn move_robot_p2(grid: &mut Grid<BiggerCell>, path: &[Direction]) {
-    let mut curr_pos: Point = grid
-        .indexed_iter()
-        .find(|(_, c)| **c == BiggerCell::Robot)
-        .map(|(pos, _)| pos.into())
-        .expect("Robot exists");
+/// every step is written to `frames` if given
+fn move_robot_p2(
+    grid: &mut Grid<BiggerCell>,
+    robot: Point,
+    path: &[Direction],
+    frames: &mut Option<Frames>,
+) {
+    let mut curr_pos = robot;
+    Frames::record_ppm(frames, grid, bigger_cell_color);
     for dir in path {
         if let Some(pos) = move_crates_p2(grid, curr_pos, (*dir).into()) {
             curr_pos = pos
         }
+        Frames::record_ppm(frames, grid, bigger_cell_color);
     }
 }
 
@@ -200,11 +224,29 @@ fn print_grid<T>(grid: &Grid<T>)
 where
     T: Debug,
 {
-    for line in grid.iter_rows() {
-        for cell in line.into_iter() {
-            print!("{:?}", cell)
-        }
-        println!()
+    println!("{}", aoc::render_with(grid, |cell| format!("{cell:?}")));
+}
+
+fn cell_color(cell: &Cell) -> Rgb {
+    match cell {
+        Cell::Wall => [90, 90, 90],
+        Cell::Crate => [200, 140, 40],
+        Cell::Robot => [255, 0, 0],
+        Cell::Empty => [0, 0, 0],
+    }
+}
+
+fn bigger_cell_color(cell: &BiggerCell) -> Rgb {
+    match cell {
+        BiggerCell::Wall => [90, 90, 90],
+        BiggerCell::CrateLeft | BiggerCell::CrateRight => [200, 140, 40],
+        BiggerCell::Robot => [255, 0, 0],
+        BiggerCell::Empty => [0, 0, 0],
+    }
+}
+
+aoc::examples! {
+    Day15 {
+        example: "example.txt", part1 = 10092, part2 = 9021;
     }
-    println!()
 }
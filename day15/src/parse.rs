@@ -1,43 +1,44 @@
 use crate::types::*;
-use aoc::Grid;
-use nom::{
-    character::{
-        complete::{anychar, line_ending},
-        streaming::one_of,
-    },
-    combinator::all_consuming,
-    multi::{many1, separated_list1},
-    IResult, Parser,
-};
+use aoc::{Direction, Grid, ParseChars, ParseError, Point};
 
-fn parse_cell(input: &str) -> IResult<&str, Cell> {
-    one_of("@#.O").map(|c| c.into()).parse(input)
+/// the warehouse, the position of the robot in it and its moves
+pub fn parse(input: &str) -> Result<(Grid<Cell>, Point, Vec<Direction>), ParseError> {
+    let (map, moves) = input
+        .split_once("\n\n")
+        .or_else(|| input.split_once("\r\n\r\n"))
+        .ok_or_else(|| ParseError::new(input, &input[input.len()..], "an empty line"))?;
+    let (mut grid, markers) = Grid::parse_chars_with_markers(map, &['@'])?;
+    let robot = markers[&'@'];
+    // the robot is moved around in the grid like the crates
+    grid[robot] = Cell::Robot;
+    let path = parse_path(input, moves)?;
+    Ok((grid, robot, path))
 }
 
-fn parse_line(input: &str) -> IResult<&str, Vec<Cell>> {
-    many1(parse_cell)(input)
+/// parse the moves, which may be split across several lines
+fn parse_path(input: &str, moves: &str) -> Result<Vec<Direction>, ParseError> {
+    moves
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .map(|(idx, c)| {
+            Direction::try_from(c)
+                .map_err(|_| ParseError::new(input, &moves[idx..], "one of `^>v<`"))
+        })
+        .collect()
 }
 
-fn parse_grid(input: &str) -> IResult<&str, Grid<Cell>> {
-    let (input, cells) = separated_list1(line_ending, parse_line)(input)?;
-    let cols = cells[0].len();
-    let flattened_cells = cells.into_iter().flatten().collect();
-    let grid: Grid<Cell> = Grid::from_vec(flattened_cells, cols);
-    Ok((input, grid))
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-pub fn parse(input: &str) -> IResult<&str, (Grid<Cell>, Vec<Direction>)> {
-    let (input, grid) = parse_grid(input)?;
-    let (input, path) = all_consuming(parse_path)(input)?;
-    Ok((input, (grid, path)))
-}
+    #[test]
+    fn test_parse_robot() {
+        let (grid, robot, path) = parse("####\n#.@#\n####\n\n<^\n>\n").unwrap();
+        assert_eq!(robot, Point(1, 2));
+        assert_eq!(grid[robot], Cell::Robot);
+        assert_eq!(path, [Direction::Left, Direction::Up, Direction::Right]);
 
-fn parse_path(input: &str) -> IResult<&str, Vec<Direction>> {
-    let (input, r_dir) = many1(anychar.map(|c| {
-        let x: Result<Direction, _> = c.try_into();
-        x
-    }))
-    .parse(input)?;
-    let dirs: Vec<Direction> = r_dir.into_iter().flat_map(|dir| dir.ok()).collect();
-    Ok((input, dirs))
+        let missing = parse("####\n#..#\n####\n\n<\n").unwrap_err();
+        assert_eq!(missing.expected, "a `@`");
+    }
 }
@@ -1,21 +1,23 @@
 use std::fmt::Debug;
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
 pub enum Cell {
     Wall,
     Crate,
     Robot,
+    #[default]
     Empty,
 }
 
-impl From<char> for Cell {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            '@' => Cell::Robot,
-            '#' => Cell::Wall,
-            '.' => Cell::Empty,
-            'O' => Cell::Crate,
-            c => panic!("found {} hex: {}", c, c as u8),
+            '#' => Ok(Cell::Wall),
+            '.' => Ok(Cell::Empty),
+            'O' => Ok(Cell::Crate),
+            c => Err(format!("found {} hex: {:x}", c, c as u32)),
         }
     }
 }
@@ -31,50 +33,6 @@ impl Debug for Cell {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-impl TryFrom<char> for Direction {
-    type Error = ();
-
-    fn try_from(value: char) -> Result<Self, Self::Error> {
-        match value {
-            '^' => Ok(Direction::Up),
-            'v' => Ok(Direction::Down),
-            '<' => Ok(Direction::Left),
-            '>' => Ok(Direction::Right),
-            _ => Err(()),
-        }
-    }
-}
-
-impl Debug for Direction {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Direction::Up => write!(f, "^"),
-            Direction::Down => write!(f, "v"),
-            Direction::Left => write!(f, "<"),
-            Direction::Right => write!(f, ">"),
-        }
-    }
-}
-
-impl From<Direction> for (i32, i32) {
-    fn from(value: Direction) -> Self {
-        match value {
-            Direction::Up => (-1, 0),
-            Direction::Down => (1, 0),
-            Direction::Left => (0, -1),
-            Direction::Right => (0, 1),
-        }
-    }
-}
-
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 pub enum BiggerCell {
     Wall,
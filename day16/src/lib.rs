@@ -0,0 +1,92 @@
+use aoc::{Answer, Direction, Grid, ParseChars, ParseError, PathFinder, Point, Solution};
+use std::fmt::Debug;
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(maze: &Self::Input) -> impl Answer {
+        let (_path, cost) = maze
+            .path_finder()
+            .shortest_path(maze.start, maze.end)
+            .unwrap();
+        cost
+    }
+
+    fn part2(maze: &Self::Input) -> impl Answer {
+        // every tile which is part of at least one of the best paths
+        let (seen, _cost) = maze
+            .path_finder()
+            .cells_on_shortest_paths(maze.start, maze.end)
+            .unwrap();
+        seen.len()
+    }
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Wall,
+    #[default]
+    Empty,
+}
+
+impl Debug for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Empty => ".",
+                Self::Wall => "#",
+            }
+        )
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            c => Err(format!("unknown cell {c}")),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Maze {
+    start: Point,
+    end: Point,
+    grid: Grid<Cell>,
+}
+
+impl Maze {
+    /// the reindeer starts facing east, every turn costs 1000 points
+    fn path_finder(&self) -> PathFinder<'_, Grid<Cell>, impl Fn(&Cell) -> Option<usize>> {
+        PathFinder::new(&self.grid, |cell| (*cell != Cell::Wall).then_some(1))
+            .with_turns(Direction::Right, 1000)
+            .with_min_cost(1)
+    }
+}
+
+fn parse(input: &str) -> Result<Maze, ParseError> {
+    let (grid, markers) = Grid::parse_chars_with_markers(input, &['S', 'E'])?;
+    Ok(Maze {
+        start: markers[&'S'],
+        end: markers[&'E'],
+        grid,
+    })
+}
+
+aoc::examples! {
+    Day16 {
+        example: "example.txt", part1 = 7036, part2 = 45;
+    }
+}
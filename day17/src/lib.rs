@@ -0,0 +1,31 @@
+use aoc::{Answer, ParseError, Solution};
+use program::Program;
+
+mod parser;
+mod part1;
+mod part2;
+mod program;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::finish(input, parser::parse(input))
+    }
+
+    fn part1(prog: &Self::Input) -> impl Answer {
+        part1::run(prog)
+    }
+
+    fn part2(prog: &Self::Input) -> impl Answer {
+        part2::run(prog)
+    }
+}
+
+aoc::examples! {
+    Day17 {
+        demo: "demo.txt", part1 = "4,6,3,5,6,3,5,2,1,0";
+    }
+}
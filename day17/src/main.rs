@@ -1,10 +0,0 @@
-mod parser;
-mod part1;
-mod part2;
-mod program;
-
-fn main() {
-    part1::run();
-
-    part2::run();
-}
@@ -1,6 +1,6 @@
 use nom::{
     bytes::complete::tag,
-    character::complete::{self, line_ending},
+    character::complete::{self, line_ending, multispace0},
     combinator::all_consuming,
     multi::separated_list1,
     sequence::{preceded, terminated},
@@ -28,5 +28,5 @@ fn parse_program(input: &str) -> IResult<&str, Program> {
 }
 
 pub fn parse(input: &str) -> IResult<&str, Program> {
-    all_consuming(parse_program)(input)
+    all_consuming(terminated(parse_program, multispace0))(input)
 }
@@ -1,11 +1,9 @@
-use crate::{parser::parse, program::result_to_string};
-
-pub fn run() {
-    let input = include_str!("../input.txt");
-    let (_input, mut prog) = parse(input).unwrap();
+use crate::program::{result_to_string, Program};
 
+pub fn run(prog: &Program) -> String {
+    let mut prog = prog.clone();
     let part1 = prog.run();
-    println!("part1: {}", result_to_string(&part1));
+    result_to_string(&part1)
 }
 
 #[cfg(test)]
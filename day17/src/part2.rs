@@ -1,11 +1,7 @@
-use crate::{parser::parse, program::Program};
+use crate::program::Program;
 
-pub fn run() {
-    let input = include_str!("../input.txt");
-    let (_input, prog) = parse(input).unwrap();
-
-    let part2 = part2(&prog);
-    println!("part2: {part2}");
+pub fn run(prog: &Program) -> u64 {
+    part2(prog)
 }
 
 fn part2(prog: &Program) -> u64 {
@@ -14,8 +10,7 @@ fn part2(prog: &Program) -> u64 {
     let mut digits_found: usize = 1;
     loop {
         // get result via decompiled formula
-        // this is faster then running the program itself
-        // difference between this and running the program:
+        // this is faster then running the program itself, `aoc bench 17` shows its timing
         //
         // Decompilation of my program
         // 2,4   b = A % 8     => B = A % 8                    => A & 7 since A mod 8 == A & 7
@@ -26,16 +21,6 @@ fn part2(prog: &Program) -> u64 {
         // 4,1   B ^= C        => B = ((A & 7) ^ 3) ^ (A >> ((A & 7) ^ 5))
         // 5,5   OUT B % 8
         //
-        // program:
-        // real    0m0,986s
-        // user    0m0,952s
-        // sys     0m0,027s
-        //
-        // this:
-        // real    0m0,118s
-        // user    0m0,101s
-        // sys     0m0,017s
-        //
         let result = (0..digits_found)
             .map(|x| {
                 let a = a >> (3 * x);
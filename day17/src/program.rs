@@ -48,7 +48,7 @@ pub struct Program {
 }
 
 impl Program {
-    pub fn new(reg: Register, code: Vec<u64>) -> Self {
+    pub(crate) fn new(reg: Register, code: Vec<u64>) -> Self {
         Self { reg, code }
     }
 
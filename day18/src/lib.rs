@@ -0,0 +1,153 @@
+use aoc::{Answer, Detailed, Grid, ParseError, PathFinder, Point, Solution};
+use serde_json::json;
+
+const START: Point = Point(0, 0);
+/// width and height of the memory space
+const SIZE: usize = 71;
+/// bytes which have fallen in part 1 and before part 2 starts searching
+const FALLEN: usize = 1024;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = FallingBytes;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(FallingBytes {
+            bytes: parse(input)?,
+            fallen: FALLEN,
+            size: SIZE,
+        })
+    }
+
+    fn part1(
+        FallingBytes {
+            bytes,
+            fallen,
+            size,
+        }: &Self::Input,
+    ) -> impl Answer {
+        let memory = Memory::new(bytes, *size);
+        memory.shortest_path(*fallen).expect("a solution exists")
+    }
+
+    fn part2(
+        FallingBytes {
+            bytes,
+            fallen,
+            size,
+        }: &Self::Input,
+    ) -> impl Answer {
+        let memory = Memory::new(bytes, *size);
+        for (time, byte) in bytes.iter().enumerate().skip(*fallen) {
+            if memory.shortest_path(time + 1).is_none() {
+                return Detailed {
+                    answer: format!("{},{}", byte.1, byte.0),
+                    details: json!({ "x": byte.1, "y": byte.0 }),
+                };
+            }
+        }
+        panic!("the path should be blocked at some point")
+    }
+}
+
+/// the bytes in the order they fall
+#[derive(Debug)]
+pub struct FallingBytes {
+    bytes: Vec<Point>,
+    fallen: usize,
+    size: usize,
+}
+
+impl FallingBytes {
+    /// let `fallen` bytes fall instead of [`FALLEN`], e.g. 12 for the example
+    pub fn with_fallen(self, fallen: usize) -> Self {
+        Self { fallen, ..self }
+    }
+
+    /// a memory space of `size` by `size` cells instead of [`SIZE`], e.g. 7 for the example
+    ///
+    /// Bytes which fall outside of it are ignored.
+    pub fn with_size(self, size: usize) -> Self {
+        Self { size, ..self }
+    }
+}
+
+/// the memory space, which reaches from the start to the bottom right corner
+struct Memory {
+    /// the time each byte falls at, `None` for cells which stay free
+    fallen: Grid<Option<usize>>,
+    goal: Point,
+}
+
+impl Memory {
+    fn new(bytes: &[Point], size: usize) -> Self {
+        let mut fallen: Grid<Option<usize>> = Grid::new(size, size);
+        for (time, byte) in bytes.iter().enumerate() {
+            if let Some(cell) = fallen.get_mut(byte.0, byte.1) {
+                cell.get_or_insert(time);
+            }
+        }
+        let goal = Point(size - 1, size - 1);
+        Self { fallen, goal }
+    }
+
+    /// number of steps to the goal after `time` bytes have fallen
+    fn shortest_path(&self, time: usize) -> Option<usize> {
+        let finder = PathFinder::new(&self.fallen, |fallen_at: &Option<usize>| {
+            fallen_at
+                .is_none_or(|fallen_at| fallen_at >= time)
+                .then_some(1)
+        })
+        .with_min_cost(1);
+        finder
+            .shortest_path(START, self.goal)
+            .map(|(_, steps)| steps)
+    }
+}
+
+fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+    input
+        .lines()
+        .map(|line| {
+            let (x, y) = line
+                .split_once(",")
+                .ok_or_else(|| ParseError::new(input, line, "two numbers separated by `,`"))?;
+            let number = |s: &str| {
+                s.parse::<usize>()
+                    .ok()
+                    .filter(|n| *n < SIZE)
+                    .ok_or_else(|| {
+                        ParseError::new(input, s, format!("a number from 0 to {}", SIZE - 1))
+                    })
+            };
+            Ok(Point(number(y)?, number(x)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goal_is_bottom_right_corner() {
+        // the only byte is the furthest one, but not in the corner
+        let bytes = Day18::parse("1,2\n").unwrap().with_size(7);
+        assert_eq!(Day18::part1(&bytes).to_string(), "12");
+    }
+
+    #[test]
+    fn test_parse_rejects_bytes_outside() {
+        let err = Day18::parse("1,1\n-2,3\n5,5\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a number from 0 to 70");
+        assert!(Day18::parse("71,0\n").is_err());
+    }
+}
+
+aoc::examples! {
+    Day18 {
+        example: "example.txt", with = |bytes| bytes.with_size(7).with_fallen(12), part1 = 22, part2 = "6,1";
+    }
+}
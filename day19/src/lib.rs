@@ -0,0 +1,92 @@
+use aoc::{Answer, ParseError, Solution};
+use cached::{proc_macro::cached, Cached};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{alpha1, line_ending},
+    multi::{many1, separated_list1},
+    sequence::separated_pair,
+    IResult,
+};
+use std::{collections::BTreeMap, sync::Arc};
+
+pub struct Day19;
+
+type LookupTable = Arc<BTreeMap<char, Vec<String>>>;
+
+impl Solution for Day19 {
+    type Input = (LookupTable, Vec<String>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let (patterns, designs) = aoc::finish(input, parse(input))?;
+        let mut lookup_table: BTreeMap<char, Vec<String>> = BTreeMap::default();
+        for pattern in patterns {
+            let first: char = pattern.chars().next().expect("not empty");
+            lookup_table
+                .entry(first)
+                .and_modify(|x| x.push(pattern.to_owned()))
+                .or_insert(vec![pattern.to_owned()]);
+        }
+        let designs = designs.into_iter().map(|d| d.to_owned()).collect();
+        Ok((Arc::new(lookup_table), designs))
+    }
+
+    fn part1((lookup_table, designs): &Self::Input) -> impl Answer {
+        designs
+            .iter()
+            .filter(|design| lookup((*design).clone(), lookup_table.clone()) > 0)
+            .count()
+    }
+
+    fn part2((lookup_table, designs): &Self::Input) -> impl Answer {
+        designs
+            .iter()
+            .map(|design| lookup(design.clone(), lookup_table.clone()))
+            .sum::<usize>()
+    }
+
+    fn reset() {
+        LOOKUP.lock().unwrap().cache_clear();
+    }
+}
+
+#[cached]
+fn lookup(current: String, lookup_table: LookupTable) -> usize {
+    if current.is_empty() {
+        return 1;
+    }
+    let first: char = current.chars().next().expect("should not be empty");
+    if let Some(pattern) = lookup_table.get(&first) {
+        pattern
+            .iter()
+            .filter(|p| p.len() <= current.len())
+            .map(|p| {
+                if current.starts_with(p) {
+                    let current = current.chars().skip(p.len()).collect::<String>();
+                    lookup(current, lookup_table.clone())
+                } else {
+                    0
+                }
+            })
+            .sum()
+    } else {
+        0
+    }
+}
+
+fn parse_pattern(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(tag(", "), alpha1)(input)
+}
+
+fn parse_design(input: &str) -> IResult<&str, Vec<&str>> {
+    separated_list1(line_ending, alpha1)(input)
+}
+
+fn parse(input: &str) -> IResult<&str, (Vec<&str>, Vec<&str>)> {
+    separated_pair(parse_pattern, many1(line_ending), parse_design)(input)
+}
+
+aoc::examples! {
+    Day19 {
+        example: "example.txt", part1 = 6, part2 = 16;
+    }
+}
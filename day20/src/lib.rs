@@ -0,0 +1,206 @@
+use aoc::{
+    Adjacency, Answer, BitGrid, Color, Grid, GridNeighbours, ParseChars, ParseError, PathFinder,
+    Point, Solution,
+};
+use rayon::prelude::*;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// picoseconds a cheat has to save to be counted
+const MIN_DIFF: usize = 100;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = RaceTrack;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(track: &Self::Input) -> impl Answer {
+        part1(track)
+    }
+
+    fn part2(track: &Self::Input) -> impl Answer {
+        part2(track)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RaceTrack {
+    grid: Grid<Cell>,
+    start: Point,
+    end: Point,
+    min_diff: usize,
+}
+
+impl RaceTrack {
+    /// count cheats which save at least `min_diff` instead of [`MIN_DIFF`], e.g. for the example
+    pub fn with_min_diff(self, min_diff: usize) -> Self {
+        Self { min_diff, ..self }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+enum Cell {
+    #[default]
+    Empty,
+    Wall,
+}
+
+fn part1(track: &RaceTrack) -> usize {
+    let walls = BitGrid::from_grid(&track.grid, |c| *c == Cell::Wall);
+    let original = search_path(track, &walls);
+    let pairs: Vec<(Point, Point)> = track
+        .grid
+        .indexed_iter()
+        .filter(|(p, c)| {
+            **c == Cell::Wall
+                && track
+                    .grid
+                    .neighbours((*p).into(), Adjacency::Cardinal)
+                    .any(|coord| track.grid[coord] == Cell::Empty)
+        })
+        .flat_map(|(p, _)| {
+            track
+                .grid
+                .neighbours(p.into(), Adjacency::Cardinal)
+                .map(move |coord| (p.into(), coord))
+        })
+        .filter(|(_, b)| track.grid[b] == Cell::Empty)
+        .collect();
+    let walls_set = pairs
+        .into_iter()
+        .map(|(a, _)| {
+            let mut tmp = walls.clone();
+            tmp.remove(a);
+            tmp
+        })
+        .collect::<HashSet<_>>();
+    walls_set
+        .into_par_iter()
+        .map(|walls| search_path(track, &walls))
+        .filter(|length| *length + track.min_diff <= original)
+        .count()
+}
+
+fn search_path(track: &RaceTrack, walls: &BitGrid) -> usize {
+    let (_path, steps) = PathFinder::new(walls, |wall| (!wall).then_some(1))
+        .with_min_cost(1)
+        .shortest_path(track.start, track.end)
+        .expect("there should be a path");
+    steps
+}
+
+impl Display for Cell {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Cell::Empty => ' ',
+                Cell::Wall => '#',
+            }
+        )
+    }
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Self::Empty),
+            '#' => Ok(Self::Wall),
+            c => Err(format!("unknown cell {c}")),
+        }
+    }
+}
+
+#[allow(dead_code)]
+fn print_grid(track: &RaceTrack, cheat_ends: &[Point]) {
+    let rendered = aoc::render(&track.grid)
+        .overlay([track.start], 'S')
+        .overlay([track.end], 'E')
+        .colored(cheat_ends.iter().copied(), 'C', Color::Red);
+    print!("{rendered}");
+}
+
+fn parse(input: &str) -> Result<RaceTrack, ParseError> {
+    let (grid, markers) = Grid::parse_chars_with_markers(input, &['S', 'E'])?;
+    Ok(RaceTrack {
+        grid,
+        start: markers[&'S'],
+        end: markers[&'E'],
+        min_diff: MIN_DIFF,
+    })
+}
+
+fn get_distances(track: &RaceTrack) -> Grid<Option<u32>> {
+    aoc::bfs(&track.grid, track.start, |_, to| {
+        track.grid[to] != Cell::Wall
+    })
+}
+
+fn part2(track: &RaceTrack) -> usize {
+    let mut count = 0_usize;
+    let dist = get_distances(track);
+    for ((r, c), _) in track.grid.indexed_iter().filter(|(_, c)| **c != Cell::Wall) {
+        let p = Point(r, c);
+
+        for radius in 2..=20 {
+            for delta_row in 0..=radius {
+                let delta_col = radius - delta_row;
+                let options: HashSet<_> = checked_surroundings(p, delta_row, delta_col)
+                    .into_iter()
+                    .filter(|x| {
+                        // bounds check and checking if its a wall
+                        x.0 < track.grid.rows()
+                            && x.1 < track.grid.cols()
+                            && track.grid[x] != Cell::Wall
+                    })
+                    .collect();
+                for next in options {
+                    // we need to save at least min_diff picoseconds + the cost of going to next itself (which is the radius)
+                    if dist[p].unwrap() as i32 - dist[next].unwrap() as i32
+                        >= track.min_diff as i32 + radius
+                    {
+                        count += 1;
+                    }
+                }
+            }
+        }
+    }
+    count
+}
+
+#[inline(always)]
+fn checked_surroundings(p: Point, delta_row: i32, delta_col: i32) -> HashSet<Point> {
+    [
+        (delta_row, delta_col),
+        (delta_row, -delta_col),
+        (-delta_row, delta_col),
+        (-delta_row, -delta_col),
+    ]
+    .into_iter()
+    .flat_map(|x| p + x)
+    .collect()
+}
+
+// Used for debugging distance grid
+#[allow(dead_code)]
+fn print_grid2(g: &Grid<Option<u32>>) {
+    let rendered = aoc::render_with(g, |c| match c {
+        Some(val) => format!("{val:3}"),
+        None => "   ".to_owned(),
+    });
+    print!("{rendered}");
+}
+
+aoc::examples! {
+    Day20 {
+        example20: "example.txt", with = |track| track.with_min_diff(20), part1 = 5;
+        example50: "example.txt", with = |track| track.with_min_diff(50), part2 = 285;
+    }
+}
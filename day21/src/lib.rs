@@ -1,23 +1,35 @@
-use cached::proc_macro::cached;
+use aoc::{Answer, Direction, ParseError, Point, Solution};
+use cached::{proc_macro::cached, Cached};
 use itertools::Itertools;
 use pathfinding::prelude::*;
 use std::{collections::HashMap, fmt::Display, iter::zip};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let inputs: Vec<Vec<KeypadButton>> = input.lines().map(parse).collect();
-
-    let part1_result: usize = inputs
-        .iter()
-        .map(|key_sequence| solve(key_sequence, 2))
-        .sum();
-    println!("part1: {part1_result}");
-
-    let part2_result: usize = inputs
-        .iter()
-        .map(|key_sequence| solve(key_sequence, 25))
-        .sum();
-    println!("part2: {part2_result}");
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Vec<KeypadButton>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.lines().map(|line| parse(input, line)).collect()
+    }
+
+    fn part1(inputs: &Self::Input) -> impl Answer {
+        inputs
+            .iter()
+            .map(|key_sequence| solve(key_sequence, 2))
+            .sum::<usize>()
+    }
+
+    fn part2(inputs: &Self::Input) -> impl Answer {
+        inputs
+            .iter()
+            .map(|key_sequence| solve(key_sequence, 25))
+            .sum::<usize>()
+    }
+
+    fn reset() {
+        COMPUTE_LEN.lock().unwrap().cache_clear();
+    }
 }
 
 fn get_num(s: &[KeypadButton]) -> usize {
@@ -141,37 +153,19 @@ fn precompute_directional_movement(
 }
 
 fn directional_pad_neighbours(button: &DirectionalButton) -> Vec<(DirectionalButton, usize)> {
-    use DirectionalButton::*;
-    match button {
-        Up => vec![Activate, Down],
-        Down => vec![Left, Up, Right],
-        Left => vec![Down],
-        Right => vec![Down, Activate],
-        Activate => vec![Up, Right],
-    }
-    .into_iter()
-    .map(|b| (b, 1))
-    .collect()
+    DIRECTIONAL_BUTTON
+        .into_iter()
+        .filter(|b| button.try_move_to(*b).is_some())
+        .map(|b| (b, 1))
+        .collect()
 }
 
 fn keypad_neighbours(button: &KeypadButton) -> Vec<(KeypadButton, usize)> {
-    use KeypadButton::*;
-    match button {
-        Zero => vec![Activate, Two],
-        One => vec![Four, Two],
-        Two => vec![One, Five, Three, Zero],
-        Three => vec![Two, Six, Activate],
-        Four => vec![Seven, Five, One],
-        Five => vec![Four, Eight, Six, Two],
-        Six => vec![Five, Nine, Three],
-        Seven => vec![Eight, Four],
-        Eight => vec![Seven, Nine, Five],
-        Nine => vec![Eight, Six],
-        Activate => vec![Zero, Three],
-    }
-    .into_iter()
-    .map(|b| (b, 1))
-    .collect()
+    KEYPAD_BUTTONS
+        .into_iter()
+        .filter(|b| button.try_move_to(*b).is_some())
+        .map(|b| (b, 1))
+        .collect()
 }
 
 fn get_input_sequence(
@@ -204,10 +198,13 @@ fn get_input_sequence(
         .collect()
 }
 
-fn parse(input: &str) -> Vec<KeypadButton> {
-    input
-        .chars()
-        .map(|c| c.try_into().expect("valid aoc input"))
+/// parse a single `line` of `input`
+fn parse(input: &str, line: &str) -> Result<Vec<KeypadButton>, ParseError> {
+    line.char_indices()
+        .map(|(idx, c)| {
+            c.try_into()
+                .map_err(|_| ParseError::new(input, &line[idx..], "one of `0-9A`"))
+        })
         .collect()
 }
 
@@ -226,60 +223,46 @@ impl Display for Sequence {
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 enum DirectionalButton {
-    Up,
-    Down,
-    Left,
-    Right,
+    Move(Direction),
     Activate,
 }
 
 const DIRECTIONAL_BUTTON: [DirectionalButton; 5] = [
-    DirectionalButton::Up,
-    DirectionalButton::Down,
-    DirectionalButton::Right,
-    DirectionalButton::Left,
+    DirectionalButton::Move(Direction::Up),
+    DirectionalButton::Move(Direction::Down),
+    DirectionalButton::Move(Direction::Right),
+    DirectionalButton::Move(Direction::Left),
     DirectionalButton::Activate,
 ];
 
 impl DirectionalButton {
-    fn try_move_to(&self, rhs: Self) -> Option<DirectionalButton> {
-        match (self, rhs) {
-            (DirectionalButton::Up, DirectionalButton::Down) => Some(DirectionalButton::Down),
-            (DirectionalButton::Up, DirectionalButton::Activate) => Some(DirectionalButton::Right),
-            (DirectionalButton::Down, DirectionalButton::Up) => Some(DirectionalButton::Up),
-            (DirectionalButton::Down, DirectionalButton::Left) => Some(DirectionalButton::Left),
-            (DirectionalButton::Down, DirectionalButton::Right) => Some(DirectionalButton::Right),
-            (DirectionalButton::Left, DirectionalButton::Down) => Some(DirectionalButton::Right),
-            (DirectionalButton::Right, DirectionalButton::Down) => Some(DirectionalButton::Left),
-            (DirectionalButton::Right, DirectionalButton::Activate) => Some(DirectionalButton::Up),
-            (DirectionalButton::Activate, DirectionalButton::Up) => Some(DirectionalButton::Left),
-            (DirectionalButton::Activate, DirectionalButton::Right) => {
-                Some(DirectionalButton::Down)
-            }
-
-            (_, _) => None,
+    /// position on the directional pad as `Point(row, column)`
+    fn position(&self) -> Point<isize> {
+        match self {
+            DirectionalButton::Move(Direction::Up) => Point(0, 1),
+            DirectionalButton::Activate => Point(0, 2),
+            DirectionalButton::Move(Direction::Left) => Point(1, 0),
+            DirectionalButton::Move(Direction::Down) => Point(1, 1),
+            DirectionalButton::Move(Direction::Right) => Point(1, 2),
         }
     }
+
+    fn try_move_to(&self, rhs: Self) -> Option<DirectionalButton> {
+        Direction::from_delta(rhs.position() - self.position()).map(DirectionalButton::Move)
+    }
 }
 
 impl Display for DirectionalButton {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                DirectionalButton::Up => "^",
-                DirectionalButton::Down => "v",
-                DirectionalButton::Left => "<",
-                DirectionalButton::Right => ">",
-                DirectionalButton::Activate => "A",
-            },
-        )
+        match self {
+            DirectionalButton::Move(direction) => write!(f, "{direction}"),
+            DirectionalButton::Activate => write!(f, "A"),
+        }
     }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-enum KeypadButton {
+pub enum KeypadButton {
     Zero,
     One,
     Two,
@@ -294,41 +277,26 @@ enum KeypadButton {
 }
 
 impl KeypadButton {
-    fn try_move_to(&self, rhs: Self) -> Option<DirectionalButton> {
-        match (self, rhs) {
-            (KeypadButton::Zero, KeypadButton::Two) => Some(DirectionalButton::Up),
-            (KeypadButton::Zero, KeypadButton::Activate) => Some(DirectionalButton::Right),
-            (KeypadButton::One, KeypadButton::Two) => Some(DirectionalButton::Right),
-            (KeypadButton::One, KeypadButton::Four) => Some(DirectionalButton::Up),
-            (KeypadButton::Two, KeypadButton::Zero) => Some(DirectionalButton::Down),
-            (KeypadButton::Two, KeypadButton::One) => Some(DirectionalButton::Left),
-            (KeypadButton::Two, KeypadButton::Three) => Some(DirectionalButton::Right),
-            (KeypadButton::Two, KeypadButton::Five) => Some(DirectionalButton::Up),
-            (KeypadButton::Three, KeypadButton::Two) => Some(DirectionalButton::Left),
-            (KeypadButton::Three, KeypadButton::Six) => Some(DirectionalButton::Up),
-            (KeypadButton::Three, KeypadButton::Activate) => Some(DirectionalButton::Down),
-            (KeypadButton::Four, KeypadButton::One) => Some(DirectionalButton::Down),
-            (KeypadButton::Four, KeypadButton::Five) => Some(DirectionalButton::Right),
-            (KeypadButton::Four, KeypadButton::Seven) => Some(DirectionalButton::Up),
-            (KeypadButton::Five, KeypadButton::Two) => Some(DirectionalButton::Down),
-            (KeypadButton::Five, KeypadButton::Four) => Some(DirectionalButton::Left),
-            (KeypadButton::Five, KeypadButton::Six) => Some(DirectionalButton::Right),
-            (KeypadButton::Five, KeypadButton::Eight) => Some(DirectionalButton::Up),
-            (KeypadButton::Six, KeypadButton::Three) => Some(DirectionalButton::Down),
-            (KeypadButton::Six, KeypadButton::Five) => Some(DirectionalButton::Left),
-            (KeypadButton::Six, KeypadButton::Nine) => Some(DirectionalButton::Up),
-            (KeypadButton::Seven, KeypadButton::Four) => Some(DirectionalButton::Down),
-            (KeypadButton::Seven, KeypadButton::Eight) => Some(DirectionalButton::Right),
-            (KeypadButton::Eight, KeypadButton::Five) => Some(DirectionalButton::Down),
-            (KeypadButton::Eight, KeypadButton::Seven) => Some(DirectionalButton::Left),
-            (KeypadButton::Eight, KeypadButton::Nine) => Some(DirectionalButton::Right),
-            (KeypadButton::Nine, KeypadButton::Six) => Some(DirectionalButton::Down),
-            (KeypadButton::Nine, KeypadButton::Eight) => Some(DirectionalButton::Left),
-            (KeypadButton::Activate, KeypadButton::Zero) => Some(DirectionalButton::Left),
-            (KeypadButton::Activate, KeypadButton::Three) => Some(DirectionalButton::Up),
-            (_, _) => None,
+    /// position on the numeric keypad as `Point(row, column)`
+    fn position(&self) -> Point<isize> {
+        match self {
+            KeypadButton::Seven => Point(0, 0),
+            KeypadButton::Eight => Point(0, 1),
+            KeypadButton::Nine => Point(0, 2),
+            KeypadButton::Four => Point(1, 0),
+            KeypadButton::Five => Point(1, 1),
+            KeypadButton::Six => Point(1, 2),
+            KeypadButton::One => Point(2, 0),
+            KeypadButton::Two => Point(2, 1),
+            KeypadButton::Three => Point(2, 2),
+            KeypadButton::Zero => Point(3, 1),
+            KeypadButton::Activate => Point(3, 2),
         }
     }
+
+    fn try_move_to(&self, rhs: Self) -> Option<DirectionalButton> {
+        Direction::from_delta(rhs.position() - self.position()).map(DirectionalButton::Move)
+    }
 }
 
 const KEYPAD_BUTTONS: [KeypadButton; 11] = [
@@ -378,7 +346,7 @@ mod tests {
     #[case("456A", 64 * 456)]
     #[case("379A", 64 * 379)]
     fn test_complete(#[case] input: &str, #[case] result: usize) {
-        let inputs = parse(input);
+        let inputs = parse(input, input).unwrap();
         assert_eq!(solve(&inputs, 2), result);
     }
 
@@ -386,13 +354,20 @@ mod tests {
     #[case("029A", vec![KeypadButton::Zero, KeypadButton::Two, KeypadButton::Nine, KeypadButton::Activate])]
     #[case("0123456789A", vec![KeypadButton::Zero, KeypadButton::One, KeypadButton::Two, KeypadButton::Three, KeypadButton::Four, KeypadButton::Five, KeypadButton::Six, KeypadButton::Seven,KeypadButton::Eight, KeypadButton::Nine, KeypadButton::Activate])]
     fn test_parse(#[case] input: &str, #[case] expected: Vec<KeypadButton>) {
-        assert_eq!(parse(input), expected)
+        assert_eq!(parse(input, input), Ok(expected))
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let input = "029A\n98xA\n";
+        let e = <Day21 as Solution>::parse(input).unwrap_err();
+        assert_eq!((e.line, e.column), (2, 3));
     }
 
     #[test]
     #[allow(non_snake_case)]
     fn test_029A() {
-        let inputs = parse("029A");
+        let inputs = parse("029A", "029A").unwrap();
         let pre_compute = precompute_keypad_movement();
         let mut optional_paths: Vec<String> = get_input_sequence(&inputs, pre_compute)
             .into_iter()
@@ -405,3 +380,9 @@ mod tests {
         )
     }
 }
+
+aoc::examples! {
+    Day21 {
+        example: "example.txt", part1 = 126384, part2 = 154115708116294_u64;
+    }
+}
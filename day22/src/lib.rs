@@ -1,45 +1,68 @@
+use aoc::{Answer, Detailed, ParseError, Solution};
 use itertools::Itertools;
 use rayon::prelude::*;
+use serde_json::json;
 use std::{collections::HashMap, iter::Iterator};
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let initial: Vec<Seed> = input
-        .lines()
-        .map(|x| Seed(x.trim().parse().unwrap()))
-        .collect();
-    let part1_result: u64 = initial
-        .par_iter()
-        .map(|x| x.into_iter().nth(1999).unwrap().0)
-        .sum();
-    println!("part1 {part1_result}");
-
-    let part2_result: ((i8, i8, i8, i8), u64) = initial
-        .clone()
-        .into_par_iter()
-        // generate all sequences for 1 starting seed
-        .flat_map(part2)
-        // fold it all into one hashmap
-        .fold(HashMap::new, |mut acc, (k, v)| {
-            acc.entry(k).or_insert(v);
-            acc
-        })
-        .reduce_with(|mut m1, m2| {
-            for (k, v) in m2 {
-                m1.entry(k).and_modify(|e| *e += v).or_insert(v);
-            }
-            m1
-        })
-        .expect("at least one sequence should exist")
-        .into_iter()
-        .max_by(|(_, v1), (_, v2)| v1.cmp(v2))
-        .expect("there should be a max");
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Seed>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let seeds: Vec<Seed> = input
+            .lines()
+            .map(|x| {
+                let x = x.trim();
+                x.parse()
+                    .map(Seed)
+                    .map_err(|_| ParseError::new(input, x, "a number"))
+            })
+            .collect::<Result<_, _>>()?;
+        if seeds.is_empty() {
+            return Err(ParseError::new(input, input, "at least one secret number"));
+        }
+        Ok(seeds)
+    }
+
+    fn part1(initial: &Self::Input) -> impl Answer {
+        initial
+            .par_iter()
+            .map(|x| x.into_iter().nth(1999).unwrap().0)
+            .sum::<u64>()
+    }
 
-    println!("part2: {} with diffs: {:?}", part2_result.1, part2_result.0);
+    fn part2(initial: &Self::Input) -> impl Answer {
+        let part2_result: ((i8, i8, i8, i8), u64) = initial
+            .clone()
+            .into_par_iter()
+            // generate all sequences for 1 starting seed
+            .flat_map(part2)
+            // fold it all into one hashmap
+            .fold(HashMap::new, |mut acc, (k, v)| {
+                acc.entry(k).or_insert(v);
+                acc
+            })
+            .reduce_with(|mut m1, m2| {
+                for (k, v) in m2 {
+                    m1.entry(k).and_modify(|e| *e += v).or_insert(v);
+                }
+                m1
+            })
+            .expect("at least one sequence should exist")
+            .into_iter()
+            .max_by(|(_, v1), (_, v2)| v1.cmp(v2))
+            .expect("there should be a max");
+        let (a, b, c, d) = part2_result.0;
+        Detailed {
+            answer: part2_result.1,
+            details: json!({ "diffs": [a, b, c, d] }),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Seed(u64);
+pub struct Seed(u64);
 
 impl Iterator for Seed {
     type Item = Seed;
@@ -121,3 +144,21 @@ fn part2(initial: Seed) -> HashMap<(i8, i8, i8, i8), u64> {
             acc
         })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty() {
+        let err = Day22::parse("").unwrap_err();
+        assert_eq!(err.expected, "at least one secret number");
+    }
+}
+
+aoc::examples! {
+    Day22 {
+        example1: "example1.txt", part1 = 37327623;
+        example2: "example2.txt", part2 = 23;
+    }
+}
@@ -1,3 +1,4 @@
+use aoc::{Answer, ParseError, Solution};
 use petgraph::{
     dot::{Config, Dot},
     prelude::*,
@@ -11,49 +12,57 @@ use std::{collections::HashMap, fs::File};
 mod maximal_cliques;
 use maximal_cliques::maximal_cliques;
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let g = parse(input);
+pub struct Day23;
 
-    // was used for debuggin purposes
-    // save_graph(&g);
+impl Solution for Day23 {
+    type Input = Graph<String, usize, Undirected>;
 
-    let part1_result = part1(g.clone());
-    println!("part1: {part1_result}");
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // was used for debuggin purposes
+        // save_graph(&g);
+        parse(input)
+    }
+
+    fn part1(g: &Self::Input) -> impl Answer {
+        part1(g)
+    }
 
-    let cliques = maximal_cliques(&g);
-    let mut code_parts: Vec<&str> = cliques
-        .iter()
-        .max_by(|a, b| a.len().cmp(&b.len()))
-        .expect("at least one clique")
-        .iter()
-        .map(|i| g[*i])
-        .collect();
-    code_parts.sort();
-    let code = code_parts.join(",");
-    println!("part2: {code}");
+    fn part2(g: &Self::Input) -> impl Answer {
+        let cliques = maximal_cliques(g);
+        let mut code_parts: Vec<&str> = cliques
+            .iter()
+            .max_by(|a, b| a.len().cmp(&b.len()))
+            .expect("at least one clique")
+            .iter()
+            .map(|i| g[*i].as_str())
+            .collect();
+        code_parts.sort();
+        code_parts.join(",")
+    }
 }
 
 #[allow(dead_code)]
-fn save_graph(g: &Graph<&str, usize, Undirected>) {
+fn save_graph(g: &Graph<String, usize, Undirected>) {
     let mut file = File::create("graph.dot").unwrap();
     write!(file, "{:?}", Dot::with_config(&g, &[Config::EdgeNoLabel])).unwrap();
 }
 
-fn parse(input: &str) -> Graph<&str, usize, Undirected> {
+fn parse(input: &str) -> Result<Graph<String, usize, Undirected>, ParseError> {
     let mut graph = Graph::new_undirected();
     let mut nodes: HashMap<&str, NodeIndex> = HashMap::default();
     for line in input.lines() {
-        let (a, b) = line.split_once("-").unwrap();
+        let (a, b) = line
+            .split_once("-")
+            .ok_or_else(|| ParseError::new(input, line, "two computers joined by `-`"))?;
         let a = if !nodes.contains_key(a) {
-            let a_idx = graph.add_node(a);
+            let a_idx = graph.add_node(a.to_owned());
             nodes.insert(a, a_idx);
             a_idx
         } else {
             *nodes.get(a).expect("contains a")
         };
         let b = if !nodes.contains_key(b) {
-            let b_idx = graph.add_node(b);
+            let b_idx = graph.add_node(b.to_owned());
             nodes.insert(b, b_idx);
             b_idx
         } else {
@@ -61,10 +70,10 @@ fn parse(input: &str) -> Graph<&str, usize, Undirected> {
         };
         graph.add_edge(a, b, 1);
     }
-    graph
+    Ok(graph)
 }
 
-fn part1(g: Graph<&str, usize, Undirected>) -> usize {
+fn part1(g: &Graph<String, usize, Undirected>) -> usize {
     let mut nodes_to_search_for = Vec::default();
     for start in g.node_indices() {
         if g[start].starts_with("t") {
@@ -86,7 +95,7 @@ fn part1(g: Graph<&str, usize, Undirected>) -> usize {
                 continue;
             }
             if path.len() == MAX_DEPTH && idx == node {
-                let mut path: Vec<_> = path.iter().map(|idx| g[*idx]).collect();
+                let mut path: Vec<_> = path.iter().map(|idx| g[*idx].as_str()).collect();
                 path.sort();
                 result.insert(path);
                 continue;
@@ -101,3 +110,9 @@ fn part1(g: Graph<&str, usize, Undirected>) -> usize {
     }
     result.len()
 }
+
+aoc::examples! {
+    Day23 {
+        example: "example.txt", part1 = 7, part2 = "co,de,ka,ta";
+    }
+}
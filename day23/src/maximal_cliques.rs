@@ -90,7 +90,7 @@ where
 /// maximal cliques.
 ///
 /// Example
-/// ```
+/// ```ignore
 /// use petgraph::algo::maximal_cliques;
 /// use petgraph::graph::UnGraph;
 /// use std::collections::HashSet;
@@ -1,3 +1,4 @@
+use aoc::{Answer, ParseError, Solution};
 use std::collections::HashMap;
 
 use nom::{
@@ -10,21 +11,32 @@ use nom::{
     IResult, Parser,
 };
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_input, mut system) = parse(input).unwrap();
-    let part1_result = system.simulate();
-    println!("part1: {part1_result}");
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = BinarySystem;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::finish(input, parse(input))
+    }
+
+    fn part1(system: &Self::Input) -> impl Answer {
+        system.simulate()
+    }
+
+    fn part2(_system: &Self::Input) -> impl Answer {
+        "unsolved"
+    }
 }
 
 #[derive(Debug)]
-struct BinarySystem {
+pub struct BinarySystem {
     inputs: HashMap<String, bool>,
     gates: Vec<Gate>,
 }
 
 impl BinarySystem {
-    fn simulate(&mut self) -> usize {
+    fn simulate(&self) -> usize {
         let mut outputs = self
             .gates
             .iter()
@@ -140,3 +152,9 @@ fn parse_gate(input: &str) -> IResult<&str, Gate> {
         },
     ))
 }
+
+aoc::examples! {
+    Day24 {
+        example: "example.txt", part1 = 4;
+    }
+}
@@ -1,3 +1,4 @@
+use aoc::{Answer, ParseError, Solution};
 use itertools::Itertools;
 use nom::{
     branch::alt,
@@ -9,19 +10,30 @@ use nom::{
     IResult,
 };
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let (_, (locks, keys)) = parse(input).unwrap();
-    let part1_result = locks
-        .into_iter()
-        .cartesian_product(keys)
-        .filter(|(k, l)| k.fits_in(l))
-        .count();
-    println!("part1: {part1_result}");
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = (Vec<Key>, Vec<Lock>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc::finish(input, parse(input))
+    }
+
+    fn part1((keys, locks): &Self::Input) -> impl Answer {
+        keys.iter()
+            .cartesian_product(locks)
+            .filter(|(k, l)| k.fits_in(l))
+            .count()
+    }
+
+    fn part2(_input: &Self::Input) -> impl Answer {
+        // there is no second puzzle on the last day
+        "unsolved"
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Key {
+pub struct Key {
     data: [u8; 5],
 }
 
@@ -38,7 +50,7 @@ impl Key {
 }
 
 #[derive(Debug, Clone, Copy)]
-struct Lock {
+pub struct Lock {
     data: [u8; 5],
 }
 
@@ -98,3 +110,9 @@ fn parse_height(input: &str) -> IResult<&str, [u8; 5]> {
     }
     Ok((input, height_info))
 }
+
+aoc::examples! {
+    Day25 {
+        example: "example.txt", part1 = 3;
+    }
+}
@@ -0,0 +1,88 @@
+use crate::days;
+use aoc::Part;
+use serde::Deserialize;
+use std::{collections::BTreeMap, fmt::Display, fs, io, path::PathBuf};
+
+/// known correct answers of a day, keyed by the name of the input file
+///
+/// Stored in `dayNN/answers.toml`:
+///
+/// ```toml
+/// ["input.txt"]
+/// part1 = 1234
+/// part2 = "abc,def"
+/// ```
+pub type Registry = BTreeMap<String, Answers>;
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+pub struct Answers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// answers can be written as plain numbers or as strings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+pub fn path(day: u8) -> PathBuf {
+    days::dir(day).join("answers.toml")
+}
+
+/// load the registry of a day, a missing file is an empty registry
+pub fn load(day: u8) -> Result<Registry, String> {
+    let path = path(day);
+    match fs::read_to_string(&path) {
+        Ok(content) => toml::from_str(&content).map_err(|e| format!("{}: {e}", path.display())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Registry::default()),
+        Err(e) => Err(format!("{}: {e}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_registry() {
+        let registry: Registry = toml::from_str(
+            r#"
+            ["input.txt"]
+            part1 = 1234
+            part2 = "abc,def"
+
+            ["demo.txt"]
+            part1 = 7
+            "#,
+        )
+        .unwrap();
+        let input = &registry["input.txt"];
+        assert_eq!(input.get(Part::One), Some(&Answer::Number(1234)));
+        assert_eq!(
+            input.get(Part::Two),
+            Some(&Answer::Text("abc,def".to_owned()))
+        );
+        assert_eq!(registry["demo.txt"].get(Part::Two), None);
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{days, input::Input};
+use aoc::{Part, Report};
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path, process::ExitCode, time::Duration};
+
+/// median time in nanoseconds for every stage of a day, keyed by `dayNN` and the stage
+///
+/// ```toml
+/// [day01]
+/// parse = 123400
+/// part1 = 5600
+/// part2 = 7800
+/// ```
+type Baseline = BTreeMap<String, BTreeMap<String, u64>>;
+
+/// the separately measured steps of a day
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{part}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stats {
+    min: Duration,
+    median: Duration,
+    max: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+        Self {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+pub struct Options<'a> {
+    /// how often every day is run
+    pub repeat: usize,
+    /// read the puzzle input from here instead of `dayNN/input.txt`
+    pub input: Option<&'a Input>,
+    /// compare against this baseline
+    pub baseline: Option<&'a Path>,
+    /// store the measured medians as new baseline
+    pub save_baseline: Option<&'a Path>,
+    /// allowed slowdown against the baseline in percent
+    pub threshold: f64,
+}
+
+/// time parse, part1 and part2 of the given days and print a table
+///
+/// Fails if a day got slower than the baseline allows.
+pub fn bench(days: &[u8], options: &Options) -> ExitCode {
+    let baseline: Baseline = match options.baseline.map(load_baseline).transpose() {
+        Ok(baseline) => baseline.unwrap_or_default(),
+        Err(e) => {
+            eprintln!("could not load baseline: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut result = ExitCode::SUCCESS;
+    let mut measured: Baseline = Baseline::new();
+    println!(
+        "{:<6} {:<6} {:>10} {:>10} {:>10} {:>10} {:>8}",
+        "day", "stage", "min", "median", "max", "baseline", "change"
+    );
+    for day in days {
+        let source = options
+            .input
+            .cloned()
+            .unwrap_or_else(|| Input::default_for(*day));
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) => {
+                eprintln!("day {day}: could not read {source}: {e}");
+                continue;
+            }
+        };
+        let reports: Vec<Report> = match (0..options.repeat)
+            .map(|_| {
+                // memoized results of the previous run would make later runs look too fast
+                days::get(*day).reset();
+                days::solve(*day, &input, &Part::ALL)
+            })
+            .collect()
+        {
+            Ok(reports) => reports,
+            Err(e) => {
+                eprintln!("day {day}: {source}: {e}");
+                result = ExitCode::FAILURE;
+                continue;
+            }
+        };
+
+        let key = format!("day{day:02}");
+        for (stage, stats) in stats(&reports) {
+            let base = baseline
+                .get(&key)
+                .and_then(|stages| stages.get(&stage.to_string()))
+                .map(|nanos| Duration::from_nanos(*nanos));
+            let change = base.map(|base| {
+                100.0 * (stats.median.as_secs_f64() / base.as_secs_f64().max(f64::EPSILON) - 1.0)
+            });
+            let regression = change.is_some_and(|change| change > options.threshold);
+            if regression {
+                result = ExitCode::FAILURE;
+            }
+            println!(
+                "{:<6} {:<6} {:>10} {:>10} {:>10} {:>10} {:>8}{}",
+                day,
+                stage.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+                base.map(format_duration).unwrap_or_default(),
+                change.map(|c| format!("{c:+.1}%")).unwrap_or_default(),
+                if regression { "  REGRESSION" } else { "" },
+            );
+            measured
+                .entry(key.clone())
+                .or_default()
+                .insert(stage.to_string(), stats.median.as_nanos() as u64);
+        }
+    }
+
+    if let Some(path) = options.save_baseline {
+        let content = toml::to_string(&measured).expect("baseline is valid toml");
+        if let Err(e) = fs::write(path, content) {
+            eprintln!("could not save baseline {}: {e}", path.display());
+            result = ExitCode::FAILURE;
+        }
+    }
+    result
+}
+
+fn stats(reports: &[Report]) -> BTreeMap<Stage, Stats> {
+    let mut samples: BTreeMap<Stage, Vec<Duration>> = BTreeMap::new();
+    for report in reports {
+        samples.entry(Stage::Parse).or_default().push(report.parse);
+        for output in report.outputs.iter() {
+            samples
+                .entry(Stage::Part(output.part))
+                .or_default()
+                .push(output.duration);
+        }
+    }
+    samples
+        .into_iter()
+        .map(|(stage, samples)| (stage, Stats::new(samples)))
+        .collect()
+}
+
+fn load_baseline(path: &Path) -> Result<Baseline, String> {
+    let content = fs::read_to_string(path).map_err(|e| format!("{}: {e}", path.display()))?;
+    toml::from_str(&content).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// short human readable duration like `12.34ms`
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    if nanos < 1e3 {
+        format!("{nanos}ns")
+    } else if nanos < 1e6 {
+        format!("{:.2}µs", nanos / 1e3)
+    } else if nanos < 1e9 {
+        format!("{:.2}ms", nanos / 1e6)
+    } else {
+        format!("{:.2}s", nanos / 1e9)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3, 2, 4].map(Duration::from_millis).to_vec();
+        let stats = Stats::new(samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.max, Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(12)), "12ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.35µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25s");
+    }
+}
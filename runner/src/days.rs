@@ -0,0 +1,187 @@
+use aoc::{ParseError, Part, Report, Solver};
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+};
+
+/// all solutions, `DAYS[0]` is day 1
+pub const DAYS: [&dyn Solver; 25] = [
+    &day01::Day01,
+    &day02::Day02,
+    &day03::Day03,
+    &day04::Day04,
+    &day05::Day05,
+    &day06::Day06,
+    &day07::Day07,
+    &day08::Day08,
+    &day09::Day09,
+    &day10::Day10,
+    &day11::Day11,
+    &day12::Day12,
+    &day13::Day13,
+    &day14::Day14,
+    &day15::Day15,
+    &day16::Day16,
+    &day17::Day17,
+    &day18::Day18,
+    &day19::Day19,
+    &day20::Day20,
+    &day21::Day21,
+    &day22::Day22,
+    &day23::Day23,
+    &day24::Day24,
+    &day25::Day25,
+];
+
+pub fn get(day: u8) -> &'static dyn Solver {
+    DAYS[day as usize - 1]
+}
+
+/// why a day could not be solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Parse(ParseError),
+    Panic(Panic),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Parse(e) => write!(f, "could not parse input\n{e}"),
+            Error::Panic(panic) => write!(f, "{panic}"),
+        }
+    }
+}
+
+/// a panic inside a solution
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Panic {
+    pub message: String,
+    /// `file:line:column`, only known while a [`PanicHook`] is installed
+    pub location: Option<String>,
+}
+
+impl Display for Panic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {location}: {}", self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+thread_local! {
+    /// whether this thread is inside [`solve`]
+    static SOLVING: Cell<bool> = const { Cell::new(false) };
+    /// the panic the hook saw last on this thread
+    static LAST_PANIC: RefCell<Option<Panic>> = const { RefCell::new(None) };
+}
+
+/// number of days being solved right now
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+/// the panic the hook saw last on another thread while a day was solved, e.g. in rayon
+static STRAY_PANIC: Mutex<Option<Panic>> = Mutex::new(None);
+
+/// panic hook which keeps panics of the solutions away from stderr
+///
+/// Their message and location end up in [`Error::Panic`] instead, so they don't get
+/// mixed into the output of other days. Panics outside of [`solve`] are printed as
+/// usual. The default hook is restored when this is dropped.
+pub struct PanicHook;
+
+impl PanicHook {
+    pub fn install() -> Self {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let panic = Panic {
+                message: message(info.payload()),
+                location: info.location().map(|l| l.to_string()),
+            };
+            if SOLVING.get() {
+                LAST_PANIC.set(Some(panic));
+            } else if ACTIVE.load(Ordering::SeqCst) > 0 {
+                *STRAY_PANIC.lock().unwrap_or_else(|e| e.into_inner()) = Some(panic);
+            } else {
+                default(info);
+            }
+        }));
+        PanicHook
+    }
+}
+
+impl Drop for PanicHook {
+    fn drop(&mut self) {
+        drop(panic::take_hook());
+    }
+}
+
+/// solve the given parts of a day
+///
+/// A panic inside the solution is caught and returned as [`Error::Panic`] so it
+/// does not take down the other days.
+pub fn solve(day: u8, input: &str, parts: &[Part]) -> Result<Report, Error> {
+    SOLVING.set(true);
+    ACTIVE.fetch_add(1, Ordering::SeqCst);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| get(day).solve(input, parts)));
+    ACTIVE.fetch_sub(1, Ordering::SeqCst);
+    SOLVING.set(false);
+    match result {
+        Ok(report) => report.map_err(Error::Parse),
+        Err(payload) => {
+            // a panic on another thread is resumed here without running the hook again
+            let panic = LAST_PANIC
+                .take()
+                .or_else(|| STRAY_PANIC.lock().unwrap_or_else(|e| e.into_inner()).take())
+                .unwrap_or_else(|| Panic {
+                    message: message(payload.as_ref()),
+                    location: None,
+                });
+            Err(Error::Panic(panic))
+        }
+    }
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|msg| msg.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown cause".to_owned())
+}
+
+/// directory of the day crate which holds its inputs and answers
+pub fn dir(day: u8) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_catches_panic() {
+        let _hook = PanicHook::install();
+        // too few bytes to ever block the path
+        let result = solve(18, "1,1\n", &[Part::Two]);
+        let Err(Error::Panic(panic)) = result else {
+            panic!("day 18 should panic, got {result:?}");
+        };
+        assert_eq!(panic.message, "the path should be blocked at some point");
+        assert!(
+            panic
+                .location
+                .as_deref()
+                .is_some_and(|location| location.starts_with("day18/src/lib.rs:")),
+            "{panic:?}"
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use aoc::{Output, Part};
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::Value;
+
+/// how results are printed
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// human readable lines
+    #[default]
+    Text,
+    /// one JSON object per line for every day and part
+    Json,
+}
+
+/// result of a single part in `--format json`
+///
+/// ```json
+/// {"day":22,"part":2,"input":"day22/input.txt","status":"ok","answer":"1234","expected":null,"duration_ns":5600,"details":{"diffs":[-2,1,-1,3]},"error":null}
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    /// `ok` or `error` for `run`, `pass`, `fail`, `missing` or `error` for `verify`
+    pub status: &'static str,
+    pub answer: Option<String>,
+    /// the stored answer, only set by `verify`
+    pub expected: Option<String>,
+    pub duration_ns: Option<u64>,
+    /// see [`aoc::Answer::details`]
+    pub details: Option<Value>,
+    pub error: Option<String>,
+}
+
+impl Record {
+    /// record without any result
+    pub fn new(day: u8, part: Part, input: String, status: &'static str) -> Self {
+        Self {
+            day,
+            part: part.into(),
+            input,
+            status,
+            answer: None,
+            expected: None,
+            duration_ns: None,
+            details: None,
+            error: None,
+        }
+    }
+
+    pub fn solved(day: u8, input: String, status: &'static str, output: &Output) -> Self {
+        Self {
+            answer: Some(output.answer.clone()),
+            duration_ns: Some(output.duration.as_nanos() as u64),
+            details: output.details.clone(),
+            ..Self::new(day, output.part, input, status)
+        }
+    }
+
+    pub fn failed(day: u8, part: Part, input: String, error: String) -> Self {
+        Self {
+            error: Some(error),
+            ..Self::new(day, part, input, "error")
+        }
+    }
+
+    pub fn print(&self) {
+        println!(
+            "{}",
+            serde_json::to_string(self).expect("records are valid json")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::time::Duration;
+
+    #[test]
+    fn test_record() {
+        let output = Output {
+            part: Part::Two,
+            answer: "23".to_owned(),
+            duration: Duration::from_micros(5),
+            details: Some(json!({ "x": 1 })),
+        };
+        let record = Record::solved(18, "input.txt".to_owned(), "ok", &output);
+        assert_eq!(
+            serde_json::to_value(&record).unwrap(),
+            json!({
+                "day": 18,
+                "part": 2,
+                "input": "input.txt",
+                "status": "ok",
+                "answer": "23",
+                "expected": null,
+                "duration_ns": 5000,
+                "details": { "x": 1 },
+                "error": null,
+            })
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use crate::days;
+use std::{
+    convert::Infallible,
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// where the puzzle input is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    /// every day reads `dayNN/input.txt` if no other input is given
+    pub fn default_for(day: u8) -> Self {
+        Self::File(days::dir(day).join("input.txt"))
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            Input::Stdin => {
+                let mut buf = String::new();
+                io::stdin().read_to_string(&mut buf)?;
+                Ok(buf)
+            }
+            Input::File(path) => fs::read_to_string(path),
+        }
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    /// `-` reads from stdin, everything else is treated as a path
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Input::Stdin),
+            path => Ok(Input::File(path.into())),
+        }
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "<stdin>"),
+            Input::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!("-".parse(), Ok(Input::Stdin));
+        assert_eq!(
+            "demo.txt".parse(),
+            Ok(Input::File(PathBuf::from("demo.txt")))
+        );
+    }
+}
@@ -0,0 +1,71 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+};
+
+/// compute `f` for every day on up to `jobs` threads
+///
+/// The results are handed to `each` on the calling thread in the order of `days`,
+/// as soon as all earlier days are done.
+pub fn ordered<T: Send>(
+    days: &[u8],
+    jobs: usize,
+    f: impl Fn(u8) -> T + Sync,
+    mut each: impl FnMut(u8, T),
+) {
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|s| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let tx = tx.clone();
+            let (next, f) = (&next, &f);
+            s.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(day) = days.get(idx) else {
+                    break;
+                };
+                if tx.send((idx, f(*day))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending: BTreeMap<usize, T> = BTreeMap::new();
+        let mut done = 0;
+        for (idx, result) in rx {
+            pending.insert(idx, result);
+            while let Some(result) = pending.remove(&done) {
+                each(days[done], result);
+                done += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_ordered() {
+        let days: Vec<u8> = (1..=10).collect();
+        let mut seen = Vec::new();
+        ordered(
+            &days,
+            4,
+            |day| {
+                // later days finish first
+                thread::sleep(Duration::from_millis(20 - day as u64));
+                day * 2
+            },
+            |day, result| seen.push((day, result)),
+        );
+        assert_eq!(seen, days.iter().map(|d| (*d, d * 2)).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,179 @@
+use aoc::Part;
+use clap::{Parser, Subcommand};
+use format::{Format, Record};
+use input::Input;
+use std::{path::PathBuf, process::ExitCode};
+
+mod answers;
+mod bench;
+mod days;
+mod format;
+mod input;
+mod jobs;
+mod verify;
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run a single day or all days
+    Run {
+        /// Day to run, runs every day if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// Only run the given part
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Read the puzzle input from this file or `-` for stdin instead of `dayNN/input.txt`
+        #[arg(short, long, requires = "day")]
+        input: Option<Input>,
+
+        /// How the results are printed
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+
+        /// Number of days solved in parallel
+        #[arg(short, long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: u64,
+
+        /// Write an image per simulation step of days 6, 14 and 15 below this directory
+        #[arg(long)]
+        frames: Option<PathBuf>,
+    },
+    /// Compare the answers of every day with the ones stored in `dayNN/answers.toml`
+    Verify {
+        /// Day to verify, verifies every day if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// How the results are printed
+        #[arg(short, long, value_enum, default_value_t)]
+        format: Format,
+    },
+    /// Time parse, part1 and part2 of every day
+    Bench {
+        /// Day to benchmark, benchmarks every day if omitted
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+
+        /// How often every day is run
+        #[arg(short = 'n', long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        repeat: u64,
+
+        /// Read the puzzle input from this file or `-` for stdin instead of `dayNN/input.txt`
+        #[arg(short, long, requires = "day")]
+        input: Option<Input>,
+
+        /// Compare the medians with a previously saved baseline
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+
+        /// Save the medians as new baseline
+        #[arg(short, long)]
+        save_baseline: Option<PathBuf>,
+
+        /// Slowdown against the baseline in percent which is reported as regression
+        #[arg(short, long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let _panics = days::PanicHook::install();
+    match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            jobs,
+            frames,
+        } => {
+            if let Some(dir) = frames {
+                // picked up by `aoc::Frames::from_env` inside the days
+                std::env::set_var(aoc::FRAMES_ENV, dir);
+            }
+            let parts: Vec<Part> = match part {
+                Some(part) => vec![part.try_into().expect("validated by clap")],
+                None => Part::ALL.to_vec(),
+            };
+            run(&selected_days(day), &parts, input, format, jobs as usize)
+        }
+        Command::Verify { day, format } => verify::verify(&selected_days(day), format),
+        Command::Bench {
+            day,
+            repeat,
+            input,
+            baseline,
+            save_baseline,
+            threshold,
+        } => {
+            let options = bench::Options {
+                repeat: repeat as usize,
+                input: input.as_ref(),
+                baseline: baseline.as_deref(),
+                save_baseline: save_baseline.as_deref(),
+                threshold,
+            };
+            bench::bench(&selected_days(day), &options)
+        }
+    }
+}
+
+fn selected_days(day: Option<u8>) -> Vec<u8> {
+    match day {
+        Some(day) => vec![day],
+        None => (1..=25).collect(),
+    }
+}
+
+fn run(days: &[u8], parts: &[Part], input: Option<Input>, format: Format, jobs: usize) -> ExitCode {
+    let mut result = ExitCode::SUCCESS;
+    let solve = |day: u8| {
+        let source = input.clone().unwrap_or_else(|| Input::default_for(day));
+        let report = source
+            .read()
+            .map_err(|e| format!("could not read {source}: {e}"))
+            .and_then(|input| {
+                days::solve(day, &input, parts).map_err(|e| format!("{source}: {e}"))
+            });
+        (source, report)
+    };
+    jobs::ordered(days, jobs, solve, |day, (source, report)| {
+        let report = match report {
+            Ok(report) => report,
+            Err(e) => {
+                match format {
+                    Format::Text => eprintln!("day {day}: {e}"),
+                    Format::Json => {
+                        for part in parts {
+                            Record::failed(day, *part, source.to_string(), e.clone()).print();
+                        }
+                    }
+                }
+                result = ExitCode::FAILURE;
+                return;
+            }
+        };
+        for output in report.outputs.iter() {
+            match format {
+                Format::Text => println!(
+                    "day {day} part {}: {} ({})",
+                    output.part,
+                    output.answer,
+                    bench::format_duration(output.duration)
+                ),
+                Format::Json => Record::solved(day, source.to_string(), "ok", output).print(),
+            }
+        }
+    });
+    result
+}
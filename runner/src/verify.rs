@@ -0,0 +1,154 @@
+use crate::{
+    answers, days,
+    format::{Format, Record},
+    input::Input,
+};
+use aoc::{Output, Part};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Display,
+    process::ExitCode,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+        actual: String,
+    },
+    /// there is no stored answer to check
+    Missing,
+    /// the input could not be read or parsed, or the solution panicked
+    Error(String),
+}
+
+impl Status {
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail { .. } => "fail",
+            Status::Missing => "missing",
+            Status::Error(_) => "error",
+        }
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => {
+                write!(f, "FAIL expected {expected} got {actual}")
+            }
+            Status::Missing => write!(f, "missing"),
+            Status::Error(_) => write!(f, "ERROR"),
+        }
+    }
+}
+
+/// check every stored answer of the given days
+///
+/// Fails if at least one answer did not match or a day could not be solved.
+pub fn verify(days: &[u8], format: Format) -> ExitCode {
+    let mut results: Vec<Status> = Vec::new();
+    for day in days {
+        let registry = match answers::load(*day) {
+            Ok(registry) => registry,
+            Err(e) => {
+                eprintln!("day {day}: could not load answers: {e}");
+                return ExitCode::FAILURE;
+            }
+        };
+        let mut files: BTreeSet<&str> = registry.keys().map(|k| k.as_str()).collect();
+        // the real input should always have an answer, so it is reported as missing
+        // even for days without input or answers
+        files.insert("input.txt");
+
+        for file in files {
+            let answers = registry.get(file).cloned().unwrap_or_default();
+            let parts: Vec<Part> = Part::ALL
+                .into_iter()
+                .filter(|part| answers.get(*part).is_some())
+                .collect();
+
+            let source = Input::File(days::dir(*day).join(file));
+            let mut failure = None;
+            let solved: BTreeMap<Part, Output> = if parts.is_empty() {
+                BTreeMap::new()
+            } else {
+                match source.read() {
+                    Ok(content) => match days::solve(*day, &content, &parts) {
+                        Ok(report) => report
+                            .outputs
+                            .into_iter()
+                            .map(|output| (output.part, output))
+                            .collect(),
+                        Err(e) => {
+                            if format == Format::Text {
+                                eprintln!("day {day}: {source}: {e}");
+                            }
+                            failure = Some(e.to_string());
+                            BTreeMap::new()
+                        }
+                    },
+                    Err(e) => {
+                        let e = format!("could not read {source}: {e}");
+                        if format == Format::Text {
+                            eprintln!("day {day}: {e}");
+                        }
+                        failure = Some(e);
+                        BTreeMap::new()
+                    }
+                }
+            };
+
+            for part in Part::ALL {
+                let expected = answers.get(part);
+                let actual = solved.get(&part);
+                let status = match (expected, actual, &failure) {
+                    (Some(_), _, Some(e)) => Status::Error(e.clone()),
+                    (Some(expected), Some(actual), _) if expected.to_string() == actual.answer => {
+                        Status::Pass
+                    }
+                    (Some(expected), Some(actual), _) => Status::Fail {
+                        expected: expected.to_string(),
+                        actual: actual.answer.clone(),
+                    },
+                    _ => Status::Missing,
+                };
+                match format {
+                    Format::Text => println!("day {day} {file} part {part}: {status}"),
+                    Format::Json => {
+                        let input = source.to_string();
+                        let mut record = match (actual, &status) {
+                            (Some(output), _) => Record::solved(*day, input, status.name(), output),
+                            (None, Status::Error(e)) => {
+                                Record::failed(*day, part, input, e.clone())
+                            }
+                            (None, _) => Record::new(*day, part, input, status.name()),
+                        };
+                        record.expected = expected.map(|e| e.to_string());
+                        record.print();
+                    }
+                }
+                results.push(status);
+            }
+        }
+    }
+
+    let count = |f: fn(&Status) -> bool| results.iter().filter(|s| f(s)).count();
+    let failed = count(|s| matches!(s, Status::Fail { .. } | Status::Error(_)));
+    if format == Format::Text {
+        println!(
+            "{} passed, {failed} failed, {} missing",
+            count(|s| *s == Status::Pass),
+            count(|s| *s == Status::Missing),
+        );
+    }
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}